@@ -0,0 +1,46 @@
+// fastmath - Various performance optimized math operations.
+// Copyright 2025 N. Dornseif
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! benchmarks
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+const BUFFER_SIZE: usize = 100_000;
+
+fn bench_generate_u64(c: &mut Criterion) {
+    let mut rn = fastmath::rng::Lehmer64::new(0);
+    let mut buffer = vec![0u64; BUFFER_SIZE];
+    c.bench_function("Benchmark filling a buffer with generate_u64", |b| {
+        b.iter(|| {
+            for pair in buffer.chunks_exact_mut(2) {
+                pair[0] = rn.generate_u64();
+                pair[1] = rn.generate_u64();
+            }
+            black_box(&buffer);
+        })
+    });
+}
+
+fn bench_generate_u64_pair(c: &mut Criterion) {
+    let mut rn = fastmath::rng::Lehmer64::new(0);
+    let mut buffer = vec![0u64; BUFFER_SIZE];
+    c.bench_function("Benchmark filling a buffer with generate_u64_pair", |b| {
+        b.iter(|| {
+            for pair in buffer.chunks_exact_mut(2) {
+                (pair[0], pair[1]) = rn.generate_u64_pair();
+            }
+            black_box(&buffer);
+        })
+    });
+}
+
+criterion_group!(benches, bench_generate_u64, bench_generate_u64_pair);
+
+criterion_main!(benches);
@@ -12,14 +12,14 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use std::hint::black_box;
 
-const SCRATCHPAD_SIZE: usize = 1_000_00;
+const SCRATCHPAD_SIZE: usize = 100_000;
 
 macro_rules! prepare_scratchpad {
     ($type:tt) => {{
         let mut rn = fastmath::rng::Lehmer64::new(0);
         let mut pad = [0 as $type; SCRATCHPAD_SIZE];
         for val in &mut pad {
-            *val = rn.generate() as $type;
+            *val = rn.generate();
         }
         pad
     }};
@@ -0,0 +1,1549 @@
+// fastmath - Various performance optimized math operations.
+// Copyright 2025 N. Dornseif
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! bits - Bit manipulation functions.
+//!
+//! Bit positions count from the least significant bit, which is position 0.
+//!
+//! # Examples
+//! ```
+//! use fastmath::bits;
+//!
+//! assert_eq!(bits::set_bit_u8(0b0000, 2), 0b0100);
+//! assert_eq!(bits::clear_bit_u16(0b0110, 1), 0b0100);
+//! assert_eq!(bits::toggle_bit_u32(0b0100, 0), 0b0101);
+//! assert!(bits::test_bit_u64(1 << 63, 63));
+//!
+//! assert_eq!(bits::extract_bits_u32(0xabcd, 4, 8), 0xbc);
+//! assert_eq!(bits::insert_bits_u32(0xabcd, 0x12, 4, 8), 0xa12d);
+//!
+//! assert_eq!(bits::gray_encode_u8(0b0110), 0b0101);
+//! assert_eq!(bits::gray_decode_u8(0b0101), 0b0110);
+//!
+//! assert_eq!(bits::morton_encode_2d_u32(0b11, 0b10), 0b1101);
+//! assert_eq!(bits::morton_decode_2d_u64(0b1101), (0b11, 0b10));
+//! ```
+
+use crate::traits::BaseInt;
+
+/// Define functions that set, clear, toggle and test a single bit.
+macro_rules! generic_bit_ops {
+    ($fnname_set:ident, $fnname_clear:ident, $fnname_toggle:ident, $fnname_test:ident, $datatype:ty) => {
+        /// Returns x with the bit at pos set to 1.
+        /// Panics in debug builds if pos is not smaller than the bit width.
+        #[inline]
+        pub const fn $fnname_set(x: $datatype, pos: u32) -> $datatype {
+            debug_assert!(pos < <$datatype>::BITS, "Bit position out of range");
+            x | (1 << pos)
+        }
+
+        /// Returns x with the bit at pos set to 0.
+        /// Panics in debug builds if pos is not smaller than the bit width.
+        #[inline]
+        pub const fn $fnname_clear(x: $datatype, pos: u32) -> $datatype {
+            debug_assert!(pos < <$datatype>::BITS, "Bit position out of range");
+            x & !(1 << pos)
+        }
+
+        /// Returns x with the bit at pos inverted.
+        /// Panics in debug builds if pos is not smaller than the bit width.
+        #[inline]
+        pub const fn $fnname_toggle(x: $datatype, pos: u32) -> $datatype {
+            debug_assert!(pos < <$datatype>::BITS, "Bit position out of range");
+            x ^ (1 << pos)
+        }
+
+        /// Returns true if the bit at pos is set.
+        /// Panics in debug builds if pos is not smaller than the bit width.
+        #[inline]
+        pub const fn $fnname_test(x: $datatype, pos: u32) -> bool {
+            debug_assert!(pos < <$datatype>::BITS, "Bit position out of range");
+            (x >> pos) & 1 == 1
+        }
+    };
+}
+
+generic_bit_ops!(set_bit_u8, clear_bit_u8, toggle_bit_u8, test_bit_u8, u8);
+generic_bit_ops!(
+    set_bit_u16,
+    clear_bit_u16,
+    toggle_bit_u16,
+    test_bit_u16,
+    u16
+);
+generic_bit_ops!(
+    set_bit_u32,
+    clear_bit_u32,
+    toggle_bit_u32,
+    test_bit_u32,
+    u32
+);
+generic_bit_ops!(
+    set_bit_u64,
+    clear_bit_u64,
+    toggle_bit_u64,
+    test_bit_u64,
+    u64
+);
+generic_bit_ops!(
+    set_bit_u128,
+    clear_bit_u128,
+    toggle_bit_u128,
+    test_bit_u128,
+    u128
+);
+generic_bit_ops!(
+    set_bit_usize,
+    clear_bit_usize,
+    toggle_bit_usize,
+    test_bit_usize,
+    usize
+);
+
+/// Define functions that extract and insert bitfields.
+macro_rules! generic_bitfield_ops {
+    ($fnname_extract:ident, $fnname_insert:ident, $datatype:ty) => {
+        /// Returns the len bits of x starting at bit start, shifted down to the least significant bits.
+        /// A len of 0 returns 0.
+        /// Panics in debug builds if start + len is larger than the bit width.
+        #[inline]
+        pub const fn $fnname_extract(x: $datatype, start: u32, len: u32) -> $datatype {
+            const BITS: u32 = <$datatype>::BITS;
+            debug_assert!(len <= BITS && start <= BITS - len, "Bitfield out of range");
+            let mask: $datatype = if len == 0 {
+                0
+            } else {
+                <$datatype>::MAX >> (BITS - len)
+            };
+            // start may equal BITS when len is 0, the mask is 0 in that case.
+            x.wrapping_shr(start) & mask
+        }
+
+        /// Returns x with the len bits starting at bit start replaced by the least significant bits of field.
+        /// Higher bits of field are ignored. A len of 0 returns x unchanged.
+        /// Panics in debug builds if start + len is larger than the bit width.
+        #[inline]
+        pub const fn $fnname_insert(
+            x: $datatype,
+            field: $datatype,
+            start: u32,
+            len: u32,
+        ) -> $datatype {
+            const BITS: u32 = <$datatype>::BITS;
+            debug_assert!(len <= BITS && start <= BITS - len, "Bitfield out of range");
+            let mask: $datatype = if len == 0 {
+                0
+            } else {
+                <$datatype>::MAX >> (BITS - len)
+            };
+            // start may equal BITS when len is 0, the mask is 0 in that case.
+            (x & !mask.wrapping_shl(start)) | (field & mask).wrapping_shl(start)
+        }
+    };
+}
+
+generic_bitfield_ops!(extract_bits_u8, insert_bits_u8, u8);
+generic_bitfield_ops!(extract_bits_u16, insert_bits_u16, u16);
+generic_bitfield_ops!(extract_bits_u32, insert_bits_u32, u32);
+generic_bitfield_ops!(extract_bits_u64, insert_bits_u64, u64);
+generic_bitfield_ops!(extract_bits_u128, insert_bits_u128, u128);
+generic_bitfield_ops!(extract_bits_usize, insert_bits_usize, usize);
+
+/// Define functions that convert to and from the reflected binary Gray code.
+macro_rules! generic_gray_code {
+    ($fnname_encode:ident, $fnname_decode:ident, $datatype:ty) => {
+        /// Returns the Gray code of x.
+        /// The Gray codes of consecutive integers differ in exactly one bit.
+        #[inline]
+        pub const fn $fnname_encode(x: $datatype) -> $datatype {
+            x ^ (x >> 1)
+        }
+
+        /// Returns the integer whose Gray code is x.
+        /// Inverse of the matching encode function, takes log2(bits) shift and xor steps.
+        #[inline]
+        pub const fn $fnname_decode(x: $datatype) -> $datatype {
+            // Every bit of the result is the xor of all bits of x at or above it.
+            let mut result = x;
+            let mut shift = 1;
+            while shift < <$datatype>::BITS {
+                result ^= result >> shift;
+                shift <<= 1;
+            }
+            result
+        }
+    };
+}
+
+generic_gray_code!(gray_encode_u8, gray_decode_u8, u8);
+generic_gray_code!(gray_encode_u16, gray_decode_u16, u16);
+generic_gray_code!(gray_encode_u32, gray_decode_u32, u32);
+generic_gray_code!(gray_encode_u64, gray_decode_u64, u64);
+generic_gray_code!(gray_encode_u128, gray_decode_u128, u128);
+generic_gray_code!(gray_encode_usize, gray_decode_usize, usize);
+
+/// Spreads the bits of x so that bit i moves to bit 2i, the odd bits are zero.
+#[inline]
+const fn spread_bits_u32(x: u32) -> u64 {
+    let mut x = x as u64;
+    x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+    x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+/// Inverse of spread_bits_u32, gathers the even bits of x. The odd bits are ignored.
+#[inline]
+const fn compact_bits_u64(x: u64) -> u32 {
+    let mut x = x & 0x5555_5555_5555_5555;
+    x = (x | (x >> 1)) & 0x3333_3333_3333_3333;
+    x = (x | (x >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x >> 4)) & 0x00ff_00ff_00ff_00ff;
+    x = (x | (x >> 8)) & 0x0000_ffff_0000_ffff;
+    (x | (x >> 16)) as u32
+}
+
+/// Spreads the bits of x so that bit i moves to bit 2i, the odd bits are zero.
+#[inline]
+const fn spread_bits_u16(x: u16) -> u32 {
+    let mut x = x as u32;
+    x = (x | (x << 8)) & 0x00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333;
+    (x | (x << 1)) & 0x5555_5555
+}
+
+/// Inverse of spread_bits_u16, gathers the even bits of x. The odd bits are ignored.
+#[inline]
+const fn compact_bits_u32(x: u32) -> u16 {
+    let mut x = x & 0x5555_5555;
+    x = (x | (x >> 1)) & 0x3333_3333;
+    x = (x | (x >> 2)) & 0x0f0f_0f0f;
+    x = (x | (x >> 4)) & 0x00ff_00ff;
+    (x | (x >> 8)) as u16
+}
+
+/// Define functions that convert between 2D coordinates and Morton codes.
+macro_rules! generic_morton_2d {
+    ($fnname_encode:ident, $fnname_decode:ident, $spread:ident, $compact:ident, $half:ty, $full:ty) => {
+        /// Returns the Morton code (Z-order curve index) of the point (x, y).
+        /// The bits of x are stored in the even bits, the bits of y in the odd bits of the result.
+        #[inline]
+        pub const fn $fnname_encode(x: $half, y: $half) -> $full {
+            $spread(x) | ($spread(y) << 1)
+        }
+
+        /// Returns the point (x, y) encoded in the Morton code z.
+        /// Inverse of the matching encode function.
+        #[inline]
+        pub const fn $fnname_decode(z: $full) -> ($half, $half) {
+            ($compact(z), $compact(z >> 1))
+        }
+    };
+}
+
+generic_morton_2d!(
+    morton_encode_2d_u16,
+    morton_decode_2d_u32,
+    spread_bits_u16,
+    compact_bits_u32,
+    u16,
+    u32
+);
+generic_morton_2d!(
+    morton_encode_2d_u32,
+    morton_decode_2d_u64,
+    spread_bits_u32,
+    compact_bits_u64,
+    u32,
+    u64
+);
+
+/// Define a function that reverses the order of bits.
+macro_rules! generic_reverse_bits {
+    ($fnname:ident, $datatype:ty) => {
+        /// Returns x with the order of its bits reversed.
+        /// The least significant bit becomes the most significant bit.
+        /// Behaviour identical to .reverse_bits().
+        #[inline]
+        pub const fn $fnname(x: $datatype) -> $datatype {
+            x.reverse_bits()
+        }
+    };
+}
+
+generic_reverse_bits!(reverse_bits_u8, u8);
+generic_reverse_bits!(reverse_bits_u16, u16);
+generic_reverse_bits!(reverse_bits_u32, u32);
+generic_reverse_bits!(reverse_bits_u64, u64);
+generic_reverse_bits!(reverse_bits_u128, u128);
+generic_reverse_bits!(reverse_bits_usize, usize);
+
+/// Returns x with the order of its bits reversed.
+/// The least significant bit becomes the most significant bit.
+#[inline]
+pub fn reverse_bits<T: BaseInt>(x: T) -> T {
+    x.reverse_bits()
+}
+
+/// Define functions that return the parity of the number of set bits.
+macro_rules! generic_bit_parity {
+    ($fnname:ident, $fnname_as_bit:ident, $datatype:ty) => {
+        /// Returns true if the number of set bits in x is odd.
+        #[inline]
+        pub const fn $fnname(x: $datatype) -> bool {
+            $fnname_as_bit(x) != 0
+        }
+
+        /// Returns 1 if the number of set bits in x is odd, 0 otherwise.
+        /// Can be used directly as an even parity bit.
+        #[inline]
+        pub const fn $fnname_as_bit(x: $datatype) -> $datatype {
+            // Fold the upper half onto the lower half until a single bit is left.
+            let mut x = x;
+            let mut shift = <$datatype>::BITS / 2;
+            while shift > 0 {
+                x ^= x >> shift;
+                shift >>= 1;
+            }
+            x & 1
+        }
+    };
+}
+
+generic_bit_parity!(bit_parity_u8, bit_parity_u8_as_bit, u8);
+generic_bit_parity!(bit_parity_u16, bit_parity_u16_as_bit, u16);
+generic_bit_parity!(bit_parity_u32, bit_parity_u32_as_bit, u32);
+generic_bit_parity!(bit_parity_u64, bit_parity_u64_as_bit, u64);
+generic_bit_parity!(bit_parity_u128, bit_parity_u128_as_bit, u128);
+generic_bit_parity!(bit_parity_usize, bit_parity_usize_as_bit, usize);
+
+/// Define functions that multiply two integers into the next larger integer type.
+macro_rules! generic_widening_mul {
+    ($fnname:ident, $fnname_hi:ident, $datatype:ty, $wide:ty) => {
+        /// Returns the full product of a and b, which can not overflow the wider type.
+        #[inline]
+        pub const fn $fnname(a: $datatype, b: $datatype) -> $wide {
+            a as $wide * b as $wide
+        }
+
+        /// Returns the upper half of the full product of a and b.
+        #[inline]
+        pub const fn $fnname_hi(a: $datatype, b: $datatype) -> $datatype {
+            ($fnname(a, b) >> <$datatype>::BITS) as $datatype
+        }
+    };
+}
+
+generic_widening_mul!(widening_mul_u8, widening_mul_u8_hi, u8, u16);
+generic_widening_mul!(widening_mul_u16, widening_mul_u16_hi, u16, u32);
+generic_widening_mul!(widening_mul_u32, widening_mul_u32_hi, u32, u64);
+generic_widening_mul!(widening_mul_u64, widening_mul_u64_hi, u64, u128);
+
+/// Define a function that counts the bits that differ between two integers.
+macro_rules! generic_hamming_distance {
+    ($fnname:ident, $datatype:ty) => {
+        /// Returns the number of bit positions at which a and b differ.
+        #[inline]
+        pub const fn $fnname(a: $datatype, b: $datatype) -> u32 {
+            (a ^ b).count_ones()
+        }
+    };
+}
+
+generic_hamming_distance!(hamming_distance_u8, u8);
+generic_hamming_distance!(hamming_distance_u16, u16);
+generic_hamming_distance!(hamming_distance_u32, u32);
+generic_hamming_distance!(hamming_distance_u64, u64);
+generic_hamming_distance!(hamming_distance_u128, u128);
+generic_hamming_distance!(hamming_distance_usize, usize);
+
+/// Returns the total number of bit positions at which the bytes of a and b differ.
+/// Panics if the slices have different lengths.
+pub fn hamming_distance_u8_slice(a: &[u8], b: &[u8]) -> u64 {
+    assert_eq!(a.len(), b.len(), "Slices must have the same length");
+    let a_chunks = a.chunks_exact(8);
+    let b_chunks = b.chunks_exact(8);
+    let mut distance: u64 = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(&x, &y)| hamming_distance_u8(x, y) as u64)
+        .sum();
+    // Compare eight bytes at once, the byte order does not change the bit count.
+    for (x, y) in a_chunks.zip(b_chunks) {
+        let x = u64::from_ne_bytes(x.try_into().unwrap());
+        let y = u64::from_ne_bytes(y.try_into().unwrap());
+        distance += hamming_distance_u64(x, y) as u64;
+    }
+    distance
+}
+
+/// Define functions that gather and scatter bits selected by a mask.
+macro_rules! generic_pext_pdep {
+    ($fnname_pext:ident, $fnname_pdep:ident, $fallback_pext:ident, $fallback_pdep:ident, $intrinsic_pext:ident, $intrinsic_pdep:ident, $datatype:ty) => {
+        /// Returns the bits of value at the positions set in mask,
+        /// packed into the least significant bits of the result.
+        /// Behaviour identical to the x86 BMI2 pext instruction.
+        /// Uses this instruction if the bmi2 target feature is enabled at compile time.
+        #[inline]
+        pub fn $fnname_pext(value: $datatype, mask: $datatype) -> $datatype {
+            #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+            {
+                // SAFETY: The bmi2 target feature is enabled, so the instruction is available.
+                #[allow(unused_unsafe)]
+                unsafe {
+                    core::arch::x86_64::$intrinsic_pext(value, mask)
+                }
+            }
+            #[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+            {
+                $fallback_pext(value, mask)
+            }
+        }
+
+        /// Returns the least significant bits of value,
+        /// scattered to the positions set in mask. All other bits are zero.
+        /// Behaviour identical to the x86 BMI2 pdep instruction.
+        /// Uses this instruction if the bmi2 target feature is enabled at compile time.
+        #[inline]
+        pub fn $fnname_pdep(value: $datatype, mask: $datatype) -> $datatype {
+            #[cfg(all(target_arch = "x86_64", target_feature = "bmi2"))]
+            {
+                // SAFETY: The bmi2 target feature is enabled, so the instruction is available.
+                #[allow(unused_unsafe)]
+                unsafe {
+                    core::arch::x86_64::$intrinsic_pdep(value, mask)
+                }
+            }
+            #[cfg(not(all(target_arch = "x86_64", target_feature = "bmi2")))]
+            {
+                $fallback_pdep(value, mask)
+            }
+        }
+
+        /// Software implementation of pext, loops over the set bits of mask.
+        #[inline]
+        #[allow(dead_code)]
+        const fn $fallback_pext(value: $datatype, mask: $datatype) -> $datatype {
+            let mut result = 0;
+            let mut mask = mask;
+            let mut bit: $datatype = 1;
+            while mask != 0 {
+                let lowest = mask & mask.wrapping_neg();
+                if value & lowest != 0 {
+                    result |= bit;
+                }
+                mask ^= lowest;
+                bit = bit.wrapping_shl(1);
+            }
+            result
+        }
+
+        /// Software implementation of pdep, loops over the set bits of mask.
+        #[inline]
+        #[allow(dead_code)]
+        const fn $fallback_pdep(value: $datatype, mask: $datatype) -> $datatype {
+            let mut result = 0;
+            let mut mask = mask;
+            let mut bit: $datatype = 1;
+            while mask != 0 {
+                let lowest = mask & mask.wrapping_neg();
+                if value & bit != 0 {
+                    result |= lowest;
+                }
+                mask ^= lowest;
+                bit = bit.wrapping_shl(1);
+            }
+            result
+        }
+    };
+}
+
+generic_pext_pdep!(
+    pext_u32,
+    pdep_u32,
+    pext_u32_fallback,
+    pdep_u32_fallback,
+    _pext_u32,
+    _pdep_u32,
+    u32
+);
+generic_pext_pdep!(
+    pext_u64,
+    pdep_u64,
+    pext_u64_fallback,
+    pdep_u64_fallback,
+    _pext_u64,
+    _pdep_u64,
+    u64
+);
+
+/// Define functions that operate on the lowest set bit.
+macro_rules! generic_lowest_set_bit {
+    ($fnname_isolate:ident, $fnname_clear:ident, $fnname_index:ident, $datatype:ty) => {
+        /// Returns x with all bits except the lowest set bit cleared.
+        /// Returns 0 if x is zero.
+        #[inline]
+        pub const fn $fnname_isolate(x: $datatype) -> $datatype {
+            x & x.wrapping_neg()
+        }
+
+        /// Returns x with the lowest set bit cleared.
+        /// Returns 0 if x is zero.
+        #[inline]
+        pub const fn $fnname_clear(x: $datatype) -> $datatype {
+            x & x.wrapping_sub(1)
+        }
+
+        /// Returns the position of the lowest set bit.
+        /// Returns None if x is zero.
+        #[inline]
+        pub const fn $fnname_index(x: $datatype) -> Option<u32> {
+            if x == 0 {
+                None
+            } else {
+                Some(x.trailing_zeros())
+            }
+        }
+    };
+}
+
+generic_lowest_set_bit!(
+    isolate_lowest_set_bit_u8,
+    clear_lowest_set_bit_u8,
+    lowest_set_bit_index_u8,
+    u8
+);
+generic_lowest_set_bit!(
+    isolate_lowest_set_bit_u16,
+    clear_lowest_set_bit_u16,
+    lowest_set_bit_index_u16,
+    u16
+);
+generic_lowest_set_bit!(
+    isolate_lowest_set_bit_u32,
+    clear_lowest_set_bit_u32,
+    lowest_set_bit_index_u32,
+    u32
+);
+generic_lowest_set_bit!(
+    isolate_lowest_set_bit_u64,
+    clear_lowest_set_bit_u64,
+    lowest_set_bit_index_u64,
+    u64
+);
+generic_lowest_set_bit!(
+    isolate_lowest_set_bit_u128,
+    clear_lowest_set_bit_u128,
+    lowest_set_bit_index_u128,
+    u128
+);
+generic_lowest_set_bit!(
+    isolate_lowest_set_bit_usize,
+    clear_lowest_set_bit_usize,
+    lowest_set_bit_index_usize,
+    usize
+);
+
+/// Define functions that test all bytes of an integer at once.
+macro_rules! generic_swar_bytes {
+    ($fnname_zero:ident, $fnname_value:ident, $fnname_broadcast:ident, $datatype:ty) => {
+        /// Returns an integer with every byte set to byte.
+        #[inline]
+        pub const fn $fnname_broadcast(byte: u8) -> $datatype {
+            // 0x0101...01
+            const LOW_BITS: $datatype = <$datatype>::MAX / 0xff;
+            byte as $datatype * LOW_BITS
+        }
+
+        /// Returns true if any byte of v is zero.
+        #[inline]
+        pub const fn $fnname_zero(v: $datatype) -> bool {
+            const LOW_BITS: $datatype = <$datatype>::MAX / 0xff;
+            const HIGH_BITS: $datatype = LOW_BITS << 7;
+            // Without a zero byte no borrow occurs, subtracting one then only sets the high bit
+            // of a byte that was zero. Bytes with a set high bit are removed by !v.
+            (v.wrapping_sub(LOW_BITS) & !v & HIGH_BITS) != 0
+        }
+
+        /// Returns true if any byte of v is equal to needle.
+        #[inline]
+        pub const fn $fnname_value(v: $datatype, needle: u8) -> bool {
+            $fnname_zero(v ^ $fnname_broadcast(needle))
+        }
+    };
+}
+
+generic_swar_bytes!(
+    has_zero_byte_u32,
+    has_byte_value_u32,
+    broadcast_byte_u32,
+    u32
+);
+generic_swar_bytes!(
+    has_zero_byte_u64,
+    has_byte_value_u64,
+    broadcast_byte_u64,
+    u64
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Defines a test function for the single bit operations.
+    macro_rules! test_bit_ops {
+        ($fnset:expr, $fnclear:expr, $fntoggle:expr, $fntest:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                const TOP: u32 = <$datatype>::BITS - 1;
+                for pos in [0, TOP] {
+                    let bit: $datatype = 1 << pos;
+                    assert_eq!($fnset(0, pos), bit, "Failed set with x=0, pos={}", pos);
+                    assert_eq!(
+                        $fnset(<$datatype>::MAX, pos),
+                        <$datatype>::MAX,
+                        "Failed set with x=MAX, pos={}",
+                        pos
+                    );
+                    assert_eq!($fnclear(0, pos), 0, "Failed clear with x=0, pos={}", pos);
+                    assert_eq!(
+                        $fnclear(<$datatype>::MAX, pos),
+                        !bit,
+                        "Failed clear with x=MAX, pos={}",
+                        pos
+                    );
+                    assert_eq!(
+                        $fntoggle(0, pos),
+                        bit,
+                        "Failed toggle with x=0, pos={}",
+                        pos
+                    );
+                    assert_eq!(
+                        $fntoggle(<$datatype>::MAX, pos),
+                        !bit,
+                        "Failed toggle with x=MAX, pos={}",
+                        pos
+                    );
+                    assert!(!$fntest(0, pos), "Failed test with x=0, pos={}", pos);
+                    assert!(
+                        $fntest(<$datatype>::MAX, pos),
+                        "Failed test with x=MAX, pos={}",
+                        pos
+                    );
+                    assert!($fntest(bit, pos), "Failed test with x=bit, pos={}", pos);
+                    assert!(!$fntest(!bit, pos), "Failed test with x=!bit, pos={}", pos);
+                }
+            }
+        };
+    }
+
+    test_bit_ops!(
+        set_bit_u8,
+        clear_bit_u8,
+        toggle_bit_u8,
+        test_bit_u8,
+        u8,
+        test_u8_bit_ops
+    );
+    test_bit_ops!(
+        set_bit_u16,
+        clear_bit_u16,
+        toggle_bit_u16,
+        test_bit_u16,
+        u16,
+        test_u16_bit_ops
+    );
+    test_bit_ops!(
+        set_bit_u32,
+        clear_bit_u32,
+        toggle_bit_u32,
+        test_bit_u32,
+        u32,
+        test_u32_bit_ops
+    );
+    test_bit_ops!(
+        set_bit_u64,
+        clear_bit_u64,
+        toggle_bit_u64,
+        test_bit_u64,
+        u64,
+        test_u64_bit_ops
+    );
+    test_bit_ops!(
+        set_bit_u128,
+        clear_bit_u128,
+        toggle_bit_u128,
+        test_bit_u128,
+        u128,
+        test_u128_bit_ops
+    );
+    test_bit_ops!(
+        set_bit_usize,
+        clear_bit_usize,
+        toggle_bit_usize,
+        test_bit_usize,
+        usize,
+        test_usize_bit_ops
+    );
+
+    /// Test that an out of range bit position panics in debug builds.
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Bit position out of range")]
+    fn test_bit_ops_position_out_of_range() {
+        set_bit_u64(0, 64);
+    }
+
+    /// Defines a test function for bitfield extract and insert.
+    macro_rules! test_bitfield_ops {
+        ($fnextract:expr, $fninsert:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                const BITS: u32 = <$datatype>::BITS;
+                let x: $datatype = <$datatype>::MAX / 3;
+                assert_eq!(
+                    $fnextract(0b1011_0100, 2, 4),
+                    0b1101,
+                    "Failed extract example"
+                );
+                assert_eq!(
+                    $fninsert(0b1111_1111, 0b0110, 2, 4),
+                    0b1101_1011,
+                    "Failed insert example"
+                );
+                assert_eq!(
+                    $fninsert(0, <$datatype>::MAX, 1, 2),
+                    0b110,
+                    "Failed insert ignoring high field bits"
+                );
+                for start in [0, 1, BITS / 2, BITS] {
+                    assert_eq!(
+                        $fnextract(x, start, 0),
+                        0,
+                        "Failed zero length with start={}",
+                        start
+                    );
+                    assert_eq!(
+                        $fninsert(x, <$datatype>::MAX, start, 0),
+                        x,
+                        "Failed zero length insert with start={}",
+                        start
+                    );
+                }
+                assert_eq!($fnextract(x, 0, BITS), x, "Failed full width extract");
+                assert_eq!($fninsert(x, !x, 0, BITS), !x, "Failed full width insert");
+                for start in 0..BITS {
+                    for len in 0..=(BITS - start) {
+                        let field = $fnextract(x, start, len);
+                        assert_eq!(
+                            $fninsert(x, field, start, len),
+                            x,
+                            "Failed round trip with start={}, len={}",
+                            start,
+                            len
+                        );
+                        assert_eq!(
+                            $fnextract($fninsert(0, <$datatype>::MAX, start, len), start, len),
+                            $fnextract(<$datatype>::MAX, 0, len),
+                            "Failed insert then extract with start={}, len={}",
+                            start,
+                            len
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    test_bitfield_ops!(extract_bits_u8, insert_bits_u8, u8, test_u8_bitfield_ops);
+    test_bitfield_ops!(
+        extract_bits_u16,
+        insert_bits_u16,
+        u16,
+        test_u16_bitfield_ops
+    );
+    test_bitfield_ops!(
+        extract_bits_u32,
+        insert_bits_u32,
+        u32,
+        test_u32_bitfield_ops
+    );
+    test_bitfield_ops!(
+        extract_bits_u64,
+        insert_bits_u64,
+        u64,
+        test_u64_bitfield_ops
+    );
+    test_bitfield_ops!(
+        extract_bits_u128,
+        insert_bits_u128,
+        u128,
+        test_u128_bitfield_ops
+    );
+    test_bitfield_ops!(
+        extract_bits_usize,
+        insert_bits_usize,
+        usize,
+        test_usize_bitfield_ops
+    );
+
+    /// Test that an out of range bitfield panics in debug builds.
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Bitfield out of range")]
+    fn test_bitfield_out_of_range() {
+        extract_bits_u64(0, 60, 5);
+    }
+
+    /// Defines a test function for Gray code encode and decode.
+    macro_rules! test_gray_code {
+        ($fnencode:expr, $fndecode:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                assert_eq!($fnencode(0), 0, "Failed encode with x=0");
+                assert_eq!($fnencode(0b0110), 0b0101, "Failed encode with x=0b0110");
+                assert_eq!(
+                    $fnencode(<$datatype>::MAX),
+                    !(<$datatype>::MAX >> 1),
+                    "Failed encode with x=MAX"
+                );
+                let mut rn = crate::rng::Lehmer64::new(0);
+                let mut values: [$datatype; 1000] = [0; 1000];
+                rn.fill(&mut values);
+                for x in values.into_iter().chain([
+                    <$datatype>::MIN,
+                    <$datatype>::MAX,
+                    <$datatype>::MAX - 1,
+                ]) {
+                    assert_eq!($fndecode($fnencode(x)), x, "Failed round trip with x={}", x);
+                    assert_eq!(
+                        $fnencode($fndecode(x)),
+                        x,
+                        "Failed reverse round trip with x={}",
+                        x
+                    );
+                    assert_eq!(
+                        ($fnencode(x) ^ $fnencode(x.wrapping_add(1))).count_ones(),
+                        1,
+                        "Failed single bit change with x={}",
+                        x
+                    );
+                }
+            }
+        };
+    }
+
+    test_gray_code!(gray_encode_u8, gray_decode_u8, u8, test_u8_gray_code);
+    test_gray_code!(gray_encode_u16, gray_decode_u16, u16, test_u16_gray_code);
+    test_gray_code!(gray_encode_u32, gray_decode_u32, u32, test_u32_gray_code);
+    test_gray_code!(gray_encode_u64, gray_decode_u64, u64, test_u64_gray_code);
+    test_gray_code!(
+        gray_encode_u128,
+        gray_decode_u128,
+        u128,
+        test_u128_gray_code
+    );
+    test_gray_code!(
+        gray_encode_usize,
+        gray_decode_usize,
+        usize,
+        test_usize_gray_code
+    );
+
+    /// Test Gray code functions exhaustively for all u8 and a contiguous range of u64.
+    #[test]
+    fn test_gray_code_exhaustive() {
+        for x in 0..=u8::MAX {
+            assert_eq!(
+                gray_decode_u8(gray_encode_u8(x)),
+                x,
+                "Failed round trip with x={}",
+                x
+            );
+            assert_eq!(
+                (gray_encode_u8(x) ^ gray_encode_u8(x.wrapping_add(1))).count_ones(),
+                1,
+                "Failed single bit change with x={}",
+                x
+            );
+        }
+        for x in (u64::MAX - 10_000)..u64::MAX {
+            assert_eq!(
+                gray_decode_u64(gray_encode_u64(x)),
+                x,
+                "Failed round trip with x={}",
+                x
+            );
+            assert_eq!(
+                (gray_encode_u64(x) ^ gray_encode_u64(x + 1)).count_ones(),
+                1,
+                "Failed single bit change with x={}",
+                x
+            );
+        }
+    }
+
+    /// Defines a test function for 2D Morton encode and decode.
+    macro_rules! test_morton_2d {
+        ($fnencode:expr, $fndecode:expr, $half:ty, $full:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                assert_eq!($fnencode(0b11, 0b10), 0b1101, "Failed with x=0b11, y=0b10");
+                assert_eq!(
+                    $fnencode(<$half>::MAX, 0),
+                    <$full>::MAX / 3,
+                    "Failed with x=MAX, y=0"
+                );
+                assert_eq!(
+                    $fnencode(0, <$half>::MAX),
+                    <$full>::MAX / 3 * 2,
+                    "Failed with x=0, y=MAX"
+                );
+                assert_eq!(
+                    $fnencode(<$half>::MAX, <$half>::MAX),
+                    <$full>::MAX,
+                    "Failed with x=MAX, y=MAX"
+                );
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..1000 {
+                    let x: $half = rn.generate();
+                    let y: $half = rn.generate();
+                    let z = $fnencode(x, y);
+                    assert_eq!(
+                        $fndecode(z),
+                        (x, y),
+                        "Failed round trip with x={}, y={}",
+                        x,
+                        y
+                    );
+                    for bit in 0..<$half>::BITS {
+                        assert_eq!(
+                            (z >> (2 * bit)) & 0b11,
+                            (((x >> bit) & 1) | (((y >> bit) & 1) << 1)) as $full,
+                            "Failed interleave with x={}, y={}, bit={}",
+                            x,
+                            y,
+                            bit
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    test_morton_2d!(
+        morton_encode_2d_u16,
+        morton_decode_2d_u32,
+        u16,
+        u32,
+        test_u16_morton_2d
+    );
+    test_morton_2d!(
+        morton_encode_2d_u32,
+        morton_decode_2d_u64,
+        u32,
+        u64,
+        test_u32_morton_2d
+    );
+
+    /// Test that consecutive coordinates trace the Z-order curve.
+    #[test]
+    fn test_morton_2d_z_order() {
+        for y in 0..4 {
+            for x in 0..4 {
+                // Within a 2x2 block the order is (0, 0), (1, 0), (0, 1), (1, 1).
+                let expected_block = (y / 2) * 2 + x / 2;
+                let expected_in_block = (y % 2) * 2 + x % 2;
+                assert_eq!(
+                    morton_encode_2d_u32(x, y),
+                    (expected_block * 4 + expected_in_block) as u64,
+                    "Failed with x={}, y={}",
+                    x,
+                    y
+                );
+            }
+        }
+    }
+
+    /// Defines a test function for bit reversal functions.
+    macro_rules! test_reverse_bits {
+        ($testfn:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                const TOP: u32 = <$datatype>::BITS - 1;
+                assert_eq!($testfn(0), 0, "Failed with x=0");
+                assert_eq!(
+                    $testfn(<$datatype>::MAX),
+                    <$datatype>::MAX,
+                    "Failed with x=MAX"
+                );
+                assert_eq!($testfn(1), 1 << TOP, "Failed with x=1");
+                assert_eq!($testfn(1 << TOP), 1, "Failed with x=MSB");
+                assert_eq!($testfn(0b1011), 0b1101 << (TOP - 3), "Failed with x=0b1011");
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..1000 {
+                    let x: $datatype = rn.generate();
+                    assert_eq!($testfn($testfn(x)), x, "Failed round trip with x={}", x);
+                    assert_eq!($testfn(x), reverse_bits(x), "Failed generic with x={}", x);
+                    for pos in 0..<$datatype>::BITS {
+                        assert_eq!(
+                            test_bit_u128(x as u128, pos),
+                            test_bit_u128($testfn(x) as u128, TOP - pos),
+                            "Failed with x={}, pos={}",
+                            x,
+                            pos
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    test_reverse_bits!(reverse_bits_u8, u8, test_u8_reverse_bits);
+    test_reverse_bits!(reverse_bits_u16, u16, test_u16_reverse_bits);
+    test_reverse_bits!(reverse_bits_u32, u32, test_u32_reverse_bits);
+    test_reverse_bits!(reverse_bits_u64, u64, test_u64_reverse_bits);
+    test_reverse_bits!(reverse_bits_u128, u128, test_u128_reverse_bits);
+    test_reverse_bits!(reverse_bits_usize, usize, test_usize_reverse_bits);
+
+    /// Test bit reversal of known palindromic and non-palindromic values.
+    #[test]
+    fn test_reverse_bits_values() {
+        assert_eq!(reverse_bits_u8(0b1000_0001), 0b1000_0001);
+        assert_eq!(reverse_bits_u8(0b1000_0000), 0b0000_0001);
+        assert_eq!(reverse_bits_u8(0b1100_1010), 0b0101_0011);
+        assert_eq!(reverse_bits_u8(0b0000_1111), 0b1111_0000);
+        assert_eq!(reverse_bits_u16(0x1234), 0x2c48);
+        assert_eq!(reverse_bits_u32(0x0000_00ff), 0xff00_0000);
+        assert_eq!(
+            reverse_bits_u64(0x0123_4567_89ab_cdef),
+            0xf7b3_d591_e6a2_c480
+        );
+    }
+
+    /// Defines a test function for bit parity functions.
+    macro_rules! test_bit_parity {
+        ($testfn:expr, $testfn_as_bit:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                assert!(!$testfn(0), "Failed with x=0");
+                assert!($testfn(1), "Failed with x=1");
+                assert!(!$testfn(0xff), "Failed with x=0xff");
+                assert!($testfn(0b1011), "Failed with x=0b1011");
+                assert!(!$testfn(<$datatype>::MAX), "Failed with x=MAX");
+                assert!($testfn(<$datatype>::MAX - 1), "Failed with x=MAX - 1");
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..1000 {
+                    let x: $datatype = rn.generate();
+                    let expected = x.count_ones() % 2;
+                    assert_eq!($testfn(x), expected == 1, "Failed with x={}", x);
+                    assert_eq!(
+                        $testfn_as_bit(x),
+                        expected as $datatype,
+                        "Failed as bit with x={}",
+                        x
+                    );
+                }
+            }
+        };
+    }
+
+    test_bit_parity!(bit_parity_u8, bit_parity_u8_as_bit, u8, test_u8_bit_parity);
+    test_bit_parity!(
+        bit_parity_u16,
+        bit_parity_u16_as_bit,
+        u16,
+        test_u16_bit_parity
+    );
+    test_bit_parity!(
+        bit_parity_u32,
+        bit_parity_u32_as_bit,
+        u32,
+        test_u32_bit_parity
+    );
+    test_bit_parity!(
+        bit_parity_u64,
+        bit_parity_u64_as_bit,
+        u64,
+        test_u64_bit_parity
+    );
+    test_bit_parity!(
+        bit_parity_u128,
+        bit_parity_u128_as_bit,
+        u128,
+        test_u128_bit_parity
+    );
+    test_bit_parity!(
+        bit_parity_usize,
+        bit_parity_usize_as_bit,
+        usize,
+        test_usize_bit_parity
+    );
+
+    /// Test that appending the parity bit always gives an even number of set bits.
+    #[test]
+    fn test_bit_parity_u8_as_bit_exhaustive() {
+        for x in 0..=u8::MAX {
+            let with_parity = ((x as u16) << 1) | bit_parity_u8_as_bit(x) as u16;
+            assert!(!bit_parity_u16(with_parity), "Failed with x={}", x);
+        }
+    }
+
+    /// Defines a test function for widening multiplication functions.
+    macro_rules! test_widening_mul {
+        ($testfn:expr, $testfn_hi:expr, $datatype:ty, $wide:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                const MAX: $datatype = <$datatype>::MAX;
+                assert_eq!($testfn(0, MAX), 0, "Failed with a=0, b=MAX");
+                assert_eq!($testfn(MAX, 1), MAX as $wide, "Failed with a=MAX, b=1");
+                assert_eq!(
+                    $testfn(MAX, 2),
+                    (MAX as $wide) << 1,
+                    "Failed with a=MAX, b=2"
+                );
+                // (2^n - 1)^2 = 2^2n - 2^(n + 1) + 1
+                assert_eq!(
+                    $testfn(MAX, MAX),
+                    (<$wide>::MAX << (<$datatype>::BITS + 1)) + 1,
+                    "Failed with a=MAX, b=MAX"
+                );
+                assert_eq!($testfn_hi(MAX, MAX), MAX - 1, "Failed hi with a=MAX, b=MAX");
+                assert_eq!($testfn_hi(MAX, 1), 0, "Failed hi with a=MAX, b=1");
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..1000 {
+                    let a: $datatype = rn.generate();
+                    let b: $datatype = rn.generate();
+                    let product = $testfn(a, b);
+                    assert_eq!(
+                        product as $datatype,
+                        a.wrapping_mul(b),
+                        "Failed low half with a={}, b={}",
+                        a,
+                        b
+                    );
+                    assert_eq!(
+                        ($testfn_hi(a, b) as $wide) << <$datatype>::BITS
+                            | a.wrapping_mul(b) as $wide,
+                        product,
+                        "Failed hi with a={}, b={}",
+                        a,
+                        b
+                    );
+                }
+            }
+        };
+    }
+
+    test_widening_mul!(
+        widening_mul_u8,
+        widening_mul_u8_hi,
+        u8,
+        u16,
+        test_u8_widening_mul
+    );
+    test_widening_mul!(
+        widening_mul_u16,
+        widening_mul_u16_hi,
+        u16,
+        u32,
+        test_u16_widening_mul
+    );
+    test_widening_mul!(
+        widening_mul_u32,
+        widening_mul_u32_hi,
+        u32,
+        u64,
+        test_u32_widening_mul
+    );
+    test_widening_mul!(
+        widening_mul_u64,
+        widening_mul_u64_hi,
+        u64,
+        u128,
+        test_u64_widening_mul
+    );
+
+    /// Test the widening multiplication of the largest u64 values.
+    #[test]
+    fn test_widening_mul_u64_max() {
+        assert_eq!(
+            widening_mul_u64(u64::MAX, u64::MAX),
+            0xffff_ffff_ffff_fffe_0000_0000_0000_0001
+        );
+        assert_eq!(widening_mul_u64_hi(u64::MAX, u64::MAX), u64::MAX - 1);
+    }
+
+    /// Defines a test function for Hamming distance functions.
+    macro_rules! test_hamming_distance {
+        ($testfn:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                const MAX: $datatype = <$datatype>::MAX;
+                assert_eq!($testfn(0, 0), 0, "Failed with a=0, b=0");
+                assert_eq!($testfn(MAX, MAX), 0, "Failed with a=MAX, b=MAX");
+                assert_eq!($testfn(0, MAX), <$datatype>::BITS, "Failed with a=0, b=MAX");
+                assert_eq!($testfn(0xff, 0x0f), 4, "Failed with a=0xff, b=0x0f");
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..1000 {
+                    let a: $datatype = rn.generate();
+                    let b: $datatype = rn.generate();
+                    assert_eq!(
+                        $testfn(a, b),
+                        $testfn(b, a),
+                        "Failed symmetry with a={}, b={}",
+                        a,
+                        b
+                    );
+                    assert_eq!(
+                        $testfn(a, b),
+                        a.count_ones() + b.count_ones() - 2 * (a & b).count_ones(),
+                        "Failed with a={}, b={}",
+                        a,
+                        b
+                    );
+                }
+            }
+        };
+    }
+
+    test_hamming_distance!(hamming_distance_u8, u8, test_u8_hamming_distance);
+    test_hamming_distance!(hamming_distance_u16, u16, test_u16_hamming_distance);
+    test_hamming_distance!(hamming_distance_u32, u32, test_u32_hamming_distance);
+    test_hamming_distance!(hamming_distance_u64, u64, test_u64_hamming_distance);
+    test_hamming_distance!(hamming_distance_u128, u128, test_u128_hamming_distance);
+    test_hamming_distance!(hamming_distance_usize, usize, test_usize_hamming_distance);
+
+    /// Test the Hamming distance of byte slices, including lengths that are not a multiple of eight.
+    #[test]
+    fn test_hamming_distance_u8_slice() {
+        assert_eq!(hamming_distance_u8_slice(b"", b""), 0);
+        assert_eq!(hamming_distance_u8_slice(b"abc", b"abd"), 3);
+        assert_eq!(hamming_distance_u8_slice(b"abc", b"abc"), 0);
+        let mut rn = crate::rng::Lehmer64::new(0);
+        let mut a = [0u8; 37];
+        let mut b = [0u8; 37];
+        rn.fill(&mut a);
+        rn.fill(&mut b);
+        for len in 0..=a.len() {
+            let expected: u64 = a[..len]
+                .iter()
+                .zip(&b[..len])
+                .map(|(&x, &y)| (x ^ y).count_ones() as u64)
+                .sum();
+            assert_eq!(
+                hamming_distance_u8_slice(&a[..len], &b[..len]),
+                expected,
+                "Failed with len={}",
+                len
+            );
+        }
+    }
+
+    /// Test that slices of different lengths panic.
+    #[test]
+    #[should_panic(expected = "Slices must have the same length")]
+    fn test_hamming_distance_u8_slice_length_mismatch() {
+        hamming_distance_u8_slice(b"ab", b"abc");
+    }
+
+    /// Defines a test function for parallel bit extract and deposit.
+    macro_rules! test_pext_pdep {
+        ($fnpext:expr, $fnpdep:expr, $fallback_pext:expr, $fallback_pdep:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                const MAX: $datatype = <$datatype>::MAX;
+                type BitFn = fn($datatype, $datatype) -> $datatype;
+                let implementations: [(BitFn, BitFn); 2] =
+                    [($fnpext, $fnpdep), ($fallback_pext, $fallback_pdep)];
+                for (pext, pdep) in implementations {
+                    assert_eq!(
+                        pext(0b1011_0110, 0b1111_0000),
+                        0b1011,
+                        "Failed pext example"
+                    );
+                    assert_eq!(
+                        pext(0b1011_0110, 0b0101_0101),
+                        0b0110,
+                        "Failed pext example"
+                    );
+                    assert_eq!(
+                        pdep(0b1011, 0b1111_0000),
+                        0b1011_0000,
+                        "Failed pdep example"
+                    );
+                    assert_eq!(
+                        pdep(0b1011, 0b0101_0101),
+                        0b0100_0101,
+                        "Failed pdep example"
+                    );
+                    assert_eq!(pext(MAX, 0), 0, "Failed pext with mask=0");
+                    assert_eq!(pdep(MAX, 0), 0, "Failed pdep with mask=0");
+                    assert_eq!(pext(MAX, MAX), MAX, "Failed pext with mask=MAX");
+                    assert_eq!(pdep(MAX, MAX), MAX, "Failed pdep with mask=MAX");
+                    assert_eq!(
+                        pext(1 << (<$datatype>::BITS - 1), 1 << (<$datatype>::BITS - 1)),
+                        1,
+                        "Failed pext of MSB"
+                    );
+                }
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..1000 {
+                    let x: $datatype = rn.generate();
+                    let mask: $datatype = rn.generate();
+                    let low_bits = MAX
+                        .checked_shr(<$datatype>::BITS - mask.count_ones())
+                        .unwrap_or(0);
+                    assert_eq!(
+                        $fnpext(x, mask),
+                        $fallback_pext(x, mask),
+                        "Failed pext with x={}, mask={}",
+                        x,
+                        mask
+                    );
+                    assert_eq!(
+                        $fnpdep(x, mask),
+                        $fallback_pdep(x, mask),
+                        "Failed pdep with x={}, mask={}",
+                        x,
+                        mask
+                    );
+                    assert_eq!(
+                        $fnpext($fnpdep(x, mask), mask),
+                        x & low_bits,
+                        "Failed round trip with x={}, mask={}",
+                        x,
+                        mask
+                    );
+                    assert_eq!(
+                        $fnpdep($fnpext(x, mask), mask),
+                        x & mask,
+                        "Failed reverse round trip with x={}, mask={}",
+                        x,
+                        mask
+                    );
+                }
+            }
+        };
+    }
+
+    test_pext_pdep!(
+        pext_u32,
+        pdep_u32,
+        pext_u32_fallback,
+        pdep_u32_fallback,
+        u32,
+        test_u32_pext_pdep
+    );
+    test_pext_pdep!(
+        pext_u64,
+        pdep_u64,
+        pext_u64_fallback,
+        pdep_u64_fallback,
+        u64,
+        test_u64_pext_pdep
+    );
+
+    /// Defines a test function for lowest set bit functions.
+    macro_rules! test_lowest_set_bit {
+        ($fnisolate:expr, $fnclear:expr, $fnindex:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                const MAX: $datatype = <$datatype>::MAX;
+                assert_eq!($fnisolate(0), 0, "Failed isolate with x=0");
+                assert_eq!($fnclear(0), 0, "Failed clear with x=0");
+                assert_eq!($fnindex(0), None, "Failed index with x=0");
+                assert_eq!($fnisolate(0b1100), 0b0100, "Failed isolate with x=0b1100");
+                assert_eq!($fnclear(0b1100), 0b1000, "Failed clear with x=0b1100");
+                assert_eq!($fnindex(0b1100), Some(2), "Failed index with x=0b1100");
+                assert_eq!($fnisolate(MAX), 1, "Failed isolate with x=MAX");
+                assert_eq!($fnclear(MAX), MAX - 1, "Failed clear with x=MAX");
+                assert_eq!($fnindex(MAX), Some(0), "Failed index with x=MAX");
+                for pos in 0..<$datatype>::BITS {
+                    let x: $datatype = 1 << pos;
+                    assert_eq!($fnisolate(x), x, "Failed isolate with x=2^{}", pos);
+                    assert_eq!($fnclear(x), 0, "Failed clear with x=2^{}", pos);
+                    assert_eq!($fnindex(x), Some(pos), "Failed index with x=2^{}", pos);
+                    // All bits at and above pos are set.
+                    let upper = MAX << pos;
+                    assert_eq!($fnisolate(upper), x, "Failed isolate with x=MAX << {}", pos);
+                    assert_eq!(
+                        $fnindex(upper),
+                        Some(pos),
+                        "Failed index with x=MAX << {}",
+                        pos
+                    );
+                }
+                // Iterating over the set bits visits every one of them exactly once.
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..1000 {
+                    let x: $datatype = rn.generate();
+                    let mut remaining = x;
+                    let mut rebuilt: $datatype = 0;
+                    let mut count = 0;
+                    while let Some(pos) = $fnindex(remaining) {
+                        assert_eq!(
+                            $fnisolate(remaining),
+                            1 << pos,
+                            "Failed isolate with x={}",
+                            remaining
+                        );
+                        rebuilt |= $fnisolate(remaining);
+                        remaining = $fnclear(remaining);
+                        count += 1;
+                    }
+                    assert_eq!(rebuilt, x, "Failed rebuild with x={}", x);
+                    assert_eq!(count, x.count_ones(), "Failed count with x={}", x);
+                }
+            }
+        };
+    }
+
+    test_lowest_set_bit!(
+        isolate_lowest_set_bit_u8,
+        clear_lowest_set_bit_u8,
+        lowest_set_bit_index_u8,
+        u8,
+        test_u8_lowest_set_bit
+    );
+    test_lowest_set_bit!(
+        isolate_lowest_set_bit_u16,
+        clear_lowest_set_bit_u16,
+        lowest_set_bit_index_u16,
+        u16,
+        test_u16_lowest_set_bit
+    );
+    test_lowest_set_bit!(
+        isolate_lowest_set_bit_u32,
+        clear_lowest_set_bit_u32,
+        lowest_set_bit_index_u32,
+        u32,
+        test_u32_lowest_set_bit
+    );
+    test_lowest_set_bit!(
+        isolate_lowest_set_bit_u64,
+        clear_lowest_set_bit_u64,
+        lowest_set_bit_index_u64,
+        u64,
+        test_u64_lowest_set_bit
+    );
+    test_lowest_set_bit!(
+        isolate_lowest_set_bit_u128,
+        clear_lowest_set_bit_u128,
+        lowest_set_bit_index_u128,
+        u128,
+        test_u128_lowest_set_bit
+    );
+    test_lowest_set_bit!(
+        isolate_lowest_set_bit_usize,
+        clear_lowest_set_bit_usize,
+        lowest_set_bit_index_usize,
+        usize,
+        test_usize_lowest_set_bit
+    );
+
+    /// Defines a test function for SWAR byte functions.
+    macro_rules! test_swar_bytes {
+        ($fnzero:expr, $fnvalue:expr, $fnbroadcast:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                const BYTES: usize = core::mem::size_of::<$datatype>();
+                assert_eq!($fnbroadcast(0), 0, "Failed broadcast with byte=0");
+                assert_eq!(
+                    $fnbroadcast(0xff),
+                    <$datatype>::MAX,
+                    "Failed broadcast with byte=0xff"
+                );
+                assert_eq!(
+                    $fnbroadcast(0xab),
+                    <$datatype>::from_ne_bytes([0xab; BYTES]),
+                    "Failed broadcast with byte=0xab"
+                );
+                assert!($fnzero(0), "Failed with v=0");
+                assert!(!$fnzero(<$datatype>::MAX), "Failed with v=MAX");
+                assert!(!$fnzero($fnbroadcast(0x80)), "Failed with v=0x8080...");
+                assert!(!$fnzero($fnbroadcast(0x01)), "Failed with v=0x0101...");
+                // A single zero byte at every position, surrounded by bytes that cause borrows.
+                for fill in [0x01, 0x80, 0xff] {
+                    for pos in 0..BYTES {
+                        let mut bytes = [fill; BYTES];
+                        bytes[pos] = 0;
+                        let v = <$datatype>::from_ne_bytes(bytes);
+                        assert!(
+                            $fnzero(v),
+                            "Failed with fill={}, zero byte at pos={}",
+                            fill,
+                            pos
+                        );
+                        bytes[pos] = 0x42;
+                        let v = <$datatype>::from_ne_bytes(bytes);
+                        assert!(
+                            $fnvalue(v, 0x42),
+                            "Failed value with fill={}, pos={}",
+                            fill,
+                            pos
+                        );
+                        assert!(
+                            !$fnvalue(v, 0x43),
+                            "Failed missing value with fill={}, pos={}",
+                            fill,
+                            pos
+                        );
+                    }
+                }
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..10_000 {
+                    // Random bytes that are zero or one with high probability.
+                    let v: $datatype = rn.generate::<$datatype>()
+                        & rn.generate::<$datatype>()
+                        & rn.generate::<$datatype>();
+                    let needle: u8 = rn.generate();
+                    let bytes = v.to_ne_bytes();
+                    assert_eq!($fnzero(v), bytes.contains(&0), "Failed with v={:#x}", v);
+                    assert_eq!(
+                        $fnvalue(v, needle),
+                        bytes.contains(&needle),
+                        "Failed value with v={:#x}, needle={:#x}",
+                        v,
+                        needle
+                    );
+                }
+            }
+        };
+    }
+
+    test_swar_bytes!(
+        has_zero_byte_u32,
+        has_byte_value_u32,
+        broadcast_byte_u32,
+        u32,
+        test_u32_swar_bytes
+    );
+    test_swar_bytes!(
+        has_zero_byte_u64,
+        has_byte_value_u64,
+        broadcast_byte_u64,
+        u64,
+        test_u64_swar_bytes
+    );
+
+    /// Test zero byte detection with the documented example values.
+    #[test]
+    fn test_has_zero_byte_values() {
+        assert!(!has_zero_byte_u64(0x0102030405060708));
+        assert!(has_zero_byte_u64(0x0102030400060708));
+        assert!(has_byte_value_u64(0x0102030405060708, 0x05));
+        assert!(!has_byte_value_u64(0x0102030405060708, 0x09));
+        assert!(!has_zero_byte_u32(0x01020304));
+        assert!(has_zero_byte_u32(0x01000304));
+    }
+}
@@ -10,7 +10,11 @@
 //! consts - Mathematical constants.
 //!
 //! Constants are available as f32 or f64.  
-//! They are the closest available floating point value to the true value.
+//! They are the closest available floating point value to the true value,
+//! the correctly rounded IEEE 754 round-to-nearest representation.  
+//! Other metallic ratios can be computed with `metallic_ratio_f64`.  
+//! Integer constants for use in hashing and random number generation are available in `integer`,
+//! IEEE 754 bit patterns and field masks in `bit_patterns`.
 //!
 //! # Examples
 //! ```
@@ -19,6 +23,27 @@
 //! // True value to 22 decimal digits:
 //! //                                       2.414213562373095048801
 //! assert_eq!(consts::float::SQRT_2_PLUS_1, 2.414213657379150390625);
+//!
+//! // Constants also defined in core have the same bit pattern.
+//! assert_eq!(consts::double::PI.to_bits(), core::f64::consts::PI.to_bits());
+//! assert_eq!(consts::double::E.to_bits(), core::f64::consts::E.to_bits());
+//! assert_eq!(consts::double::TAU.to_bits(), core::f64::consts::TAU.to_bits());
+//! assert_eq!(consts::float::PI.to_bits(), core::f32::consts::PI.to_bits());
+//! assert_eq!(consts::float::E.to_bits(), core::f32::consts::E.to_bits());
+//! assert_eq!(consts::float::TAU.to_bits(), core::f32::consts::TAU.to_bits());
+//!
+//! // The algebraic constants satisfy their defining equation to within one ULP.
+//! // Both sides lie in [2, 4), where one ULP is 2 * EPSILON.
+//! let phi = consts::double::GOLDEN_RATIO;
+//! assert!((phi * phi - (phi + 1.0)).abs() <= 2.0 * f64::EPSILON);
+//! let rho = consts::double::PLASTIC_NUMBER;
+//! assert!((rho * rho * rho - (rho + 1.0)).abs() <= 2.0 * f64::EPSILON);
+//!
+//! // Inverse powers of two are exact.
+//! assert_eq!(consts::double::INV_2POW53, 2f64.powi(-53));
+//! assert_eq!(consts::double::INV_2POW64, 2f64.powi(-64));
+//! assert_eq!(consts::float::INV_2POW24, 2f32.powi(-24));
+//! assert_eq!(consts::float::INV_2POW32, 2f32.powi(-32));
 //! ```
 
 // More digits for reference:
@@ -36,6 +61,84 @@
 //
 // SUPERSILVER_RATIO:
 // 2.20556943040059031170202861778382342637710891959769944047055220355183479035
+//
+// PLASTIC_NUMBER:
+// 1.32471795724474602596090885447809734073440405690173336453401505030282785125
+//
+// GOLDEN_RATIO:
+// 1.61803398874989484820458683436563811772030917980576286213544862270526046282
+//
+// GOLDEN_RATIO_CONJUGATE:
+// 0.61803398874989484820458683436563811772030917980576286213544862270526046282
+//
+// SQRT_5:
+// 2.23606797749978969640917366873127623544061835961152572427089724541052092564
+//
+// E:
+// 2.71828182845904523536028747135266249775724709369995957496696762772407663035
+//
+// LOG2_E:
+// 1.44269504088896340735992468100189213742664595415298593413544940693110921918
+//
+// LOG10_E:
+// 0.434294481903251827651128918916605082294397005803666566114453783165864649209
+//
+// LN_2:
+// 0.693147180559945309417232121458176568075500134360255254120680009493393621970
+//
+// LN_10:
+// 2.30258509299404568401799145468436420760110148862877297603332790096757260968
+//
+// PI:
+// 3.14159265358979323846264338327950288419716939937510582097494459230781640629
+//
+// TAU:
+// 6.28318530717958647692528676655900576839433879875021164194988918461563281257
+//
+// FRAC_PI_2:
+// 1.57079632679489661923132169163975144209858469968755291048747229615390820314
+//
+// FRAC_PI_3:
+// 1.04719755119659774615421446109316762806572313312503527365831486410260546876
+//
+// FRAC_PI_4:
+// 0.785398163397448309615660845819875721049292349843776455243736148076954101572
+//
+// FRAC_PI_6:
+// 0.523598775598298873077107230546583814032861566562517636829157432051302734381
+//
+// FRAC_PI_8:
+// 0.392699081698724154807830422909937860524646174921888227621868074038477050786
+//
+// FRAC_1_PI:
+// 0.318309886183790671537767526745028724068919291480912897495334688117793595268
+//
+// FRAC_2_PI:
+// 0.636619772367581343075535053490057448137838582961825794990669376235587190537
+//
+// FRAC_2_SQRT_PI:
+// 1.12837916709551257389615890312154517168810125865799771368817144342128493688
+//
+// SQRT_2:
+// 1.41421356237309504880168872420969807856967187537694807317667973799073247846
+//
+// SQRT_3:
+// 1.73205080756887729352744634150587236694280525381038062805580697945193301691
+//
+// FRAC_1_SQRT_2:
+// 0.707106781186547524400844362104849039284835937688474036588339868995366239231
+//
+// FRAC_SQRT_3_2:
+// 0.866025403784438646763723170752936183471402626905190314027903489725966508454
+//
+// EULER_MASCHERONI:
+// 0.577215664901532860606512090082402431042159335939923598805767234884867726778
+//
+// CATALAN:
+// 0.915965594177219015054603514932384110774149374281672134266498119621763019776
+//
+// APERY:
+// 1.20205690315959428539973816151144999076498629234049888179227155534183820579
 
 /// Double precision (f64) constants.
 pub mod double {
@@ -60,6 +163,113 @@ pub mod double {
     /// Exact double representation: 2.205569430400590391627702047117054462432861328125
     pub const SUPERSILVER_RATIO: f64 = f64::from_bits(0x4001a50195e505e8);
 
+    /// The plastic number. The real solution of the equation x^3 = x + 1.  
+    /// Exact double representation: 1.3247179572447460582651501681539230048656463623046875
+    pub const PLASTIC_NUMBER: f64 = f64::from_bits(0x3ff5320b74eca44b);
+
+    /// The golden ratio. The positive solution of the equation x^2 = x + 1.  
+    /// Exact double representation: 1.6180339887498949025257388711906969547271728515625
+    pub const GOLDEN_RATIO: f64 = f64::from_bits(0x3ff9e3779b97f4a8);
+
+    /// The golden ratio conjugate. Equal to one over the golden ratio and the golden ratio minus one.  
+    /// Exact double representation: 0.6180339887498949025257388711906969547271728515625
+    pub const GOLDEN_RATIO_CONJUGATE: f64 = f64::from_bits(0x3fe3c6ef372fe950);
+
+    /// The square root of five  
+    /// Exact double representation: 2.236067977499789805051477742381393909454345703125
+    pub const SQRT_5: f64 = f64::from_bits(0x4001e3779b97f4a8);
+
+    /// Euler's number, the base of the natural logarithm.  
+    /// Exact double representation: 2.718281828459045090795598298427648842334747314453125
+    pub const E: f64 = f64::from_bits(0x4005bf0a8b145769);
+
+    /// The base two logarithm of e.  
+    /// Exact double representation: 1.442695040888963387004650940070860087871551513671875
+    pub const LOG2_E: f64 = f64::from_bits(0x3ff71547652b82fe);
+
+    /// The base ten logarithm of e.  
+    /// Exact double representation: 0.43429448190325181666793241674895398318767547607421875
+    pub const LOG10_E: f64 = f64::from_bits(0x3fdbcb7b1526e50e);
+
+    /// The natural logarithm of two.  
+    /// Exact double representation: 0.69314718055994528622676398299518041312694549560546875
+    pub const LN_2: f64 = f64::from_bits(0x3fe62e42fefa39ef);
+
+    /// The natural logarithm of ten.  
+    /// Exact double representation: 2.30258509299404590109361379290930926799774169921875
+    pub const LN_10: f64 = f64::from_bits(0x40026bb1bbb55516);
+
+    /// Archimedes' constant, the ratio of a circle's circumference to its diameter.  
+    /// Exact double representation: 3.141592653589793115997963468544185161590576171875
+    pub const PI: f64 = f64::from_bits(0x400921fb54442d18);
+
+    /// The full circle constant, equal to two times pi.  
+    /// Exact double representation: 6.28318530717958623199592693708837032318115234375
+    pub const TAU: f64 = f64::from_bits(0x401921fb54442d18);
+
+    /// Pi divided by two  
+    /// Exact double representation: 1.5707963267948965579989817342720925807952880859375
+    pub const FRAC_PI_2: f64 = f64::from_bits(0x3ff921fb54442d18);
+
+    /// Pi divided by three  
+    /// Exact double representation: 1.047197551196597853362391106202267110347747802734375
+    pub const FRAC_PI_3: f64 = f64::from_bits(0x3ff0c152382d7366);
+
+    /// Pi divided by four  
+    /// Exact double representation: 0.78539816339744827899949086713604629039764404296875
+    pub const FRAC_PI_4: f64 = f64::from_bits(0x3fe921fb54442d18);
+
+    /// Pi divided by six  
+    /// Exact double representation: 0.5235987755982989266811955531011335551738739013671875
+    pub const FRAC_PI_6: f64 = f64::from_bits(0x3fe0c152382d7366);
+
+    /// Pi divided by eight  
+    /// Exact double representation: 0.392699081698724139499745433568023145198822021484375
+    pub const FRAC_PI_8: f64 = f64::from_bits(0x3fd921fb54442d18);
+
+    /// One divided by pi  
+    /// Exact double representation: 0.318309886183790691216444201927515678107738494873046875
+    pub const FRAC_1_PI: f64 = f64::from_bits(0x3fd45f306dc9c883);
+
+    /// Two divided by pi  
+    /// Exact double representation: 0.63661977236758138243288840385503135621547698974609375
+    pub const FRAC_2_PI: f64 = f64::from_bits(0x3fe45f306dc9c883);
+
+    /// Two divided by the square root of pi  
+    /// Exact double representation: 1.1283791670955125585606992899556644260883331298828125
+    pub const FRAC_2_SQRT_PI: f64 = f64::from_bits(0x3ff20dd750429b6d);
+
+    /// The square root of two  
+    /// Exact double representation: 1.4142135623730951454746218587388284504413604736328125
+    pub const SQRT_2: f64 = f64::from_bits(0x3ff6a09e667f3bcd);
+
+    /// The square root of three  
+    /// Exact double representation: 1.732050807568877193176604123436845839023590087890625
+    pub const SQRT_3: f64 = f64::from_bits(0x3ffbb67ae8584caa);
+
+    /// One divided by the square root of two, equal to half the square root of two  
+    /// Exact double representation: 0.70710678118654757273731092936941422522068023681640625
+    pub const FRAC_1_SQRT_2: f64 = f64::from_bits(0x3fe6a09e667f3bcd);
+
+    /// The square root of three divided by two  
+    /// Exact double representation: 0.8660254037844385965883020617184229195117950439453125
+    pub const FRAC_SQRT_3_2: f64 = f64::from_bits(0x3febb67ae8584caa);
+
+    /// The Euler-Mascheroni constant gamma.
+    /// The limit of the harmonic series 1 + 1/2 + ... + 1/n minus ln(n) as n goes to infinity.  
+    /// Exact double representation: 0.57721566490153286554942724251304753124713897705078125
+    pub const EULER_MASCHERONI: f64 = f64::from_bits(0x3fe2788cfc6fb619);
+
+    /// Catalan's constant G.
+    /// The sum of the alternating series 1 - 1/3^2 + 1/5^2 - 1/7^2 + ...  
+    /// Exact double representation: 0.91596559417721901130704509341740049421787261962890625
+    pub const CATALAN: f64 = f64::from_bits(0x3fed4f9713e8135d);
+
+    /// Apery's constant zeta(3).
+    /// The sum of the series 1 + 1/2^3 + 1/3^3 + 1/4^3 + ...  
+    /// Exact double representation: 1.2020569031595942366408280577161349356174468994140625
+    pub const APERY: f64 = f64::from_bits(0x3ff33ba004f00621);
+
     /// One over 2 to the 53th power. Equivalent to 1.0 / (1u64 << 53) as f64.  
     /// Exact double representation: 1.1102230246251565404236316680908203125E-16
     pub const INV_2POW53: f64 = f64::from_bits(0x3ca0000000000000);
@@ -67,6 +277,27 @@ pub mod double {
     /// One over 2 to the 24th power. Equivalent to 1.0 / (1u64 << 24) as f64.  
     /// Exact double representation: 5.9604644775390625E-8
     pub const INV_2POW24: f64 = f64::from_bits(0x3e70000000000000);
+
+    /// One over 2 to the 8th power. Equivalent to 1.0 / (1u64 << 8) as f64.  
+    /// Exact double representation: 0.00390625
+    pub const INV_2POW8: f64 = f64::from_bits(0x3f70000000000000);
+
+    /// One over 2 to the 16th power. Equivalent to 1.0 / (1u64 << 16) as f64.  
+    /// Exact double representation: 1.52587890625E-5
+    pub const INV_2POW16: f64 = f64::from_bits(0x3ef0000000000000);
+
+    /// One over 2 to the 32th power. Equivalent to 1.0 / (1u64 << 32) as f64.  
+    /// Exact double representation: 2.3283064365386962890625E-10
+    pub const INV_2POW32: f64 = f64::from_bits(0x3df0000000000000);
+
+    /// One over 2 to the 63th power. Equivalent to 1.0 / (1u64 << 63) as f64.  
+    /// Exact double representation: 1.08420217248550443400745280086994171142578125E-19
+    pub const INV_2POW63: f64 = f64::from_bits(0x3c00000000000000);
+
+    /// One over 2 to the 64th power. Equivalent to 1.0 / (1u128 << 64) as f64.  
+    /// Not zero, 2^-64 is well within the range of normal double values.  
+    /// Exact double representation: 5.42101086242752217003726400434970855712890625E-20
+    pub const INV_2POW64: f64 = f64::from_bits(0x3bf0000000000000);
 }
 
 /// Single precision (f32) constants.
@@ -91,6 +322,113 @@ pub mod float {
     /// Exact float representation: 2.2055695056915283203125
     pub const SUPERSILVER_RATIO: f32 = f32::from_bits(0x400d280d);
 
+    /// The plastic number. The real solution of the equation x^3 = x + 1.  
+    /// Exact float representation: 1.324717998504638671875
+    pub const PLASTIC_NUMBER: f32 = f32::from_bits(0x3fa9905c);
+
+    /// The golden ratio. The positive solution of the equation x^2 = x + 1.  
+    /// Exact float representation: 1.61803400516510009765625
+    pub const GOLDEN_RATIO: f32 = f32::from_bits(0x3fcf1bbd);
+
+    /// The golden ratio conjugate. Equal to one over the golden ratio and the golden ratio minus one.  
+    /// Exact float representation: 0.61803400516510009765625
+    pub const GOLDEN_RATIO_CONJUGATE: f32 = f32::from_bits(0x3f1e377a);
+
+    /// The square root of five  
+    /// Exact float representation: 2.2360680103302001953125
+    pub const SQRT_5: f32 = f32::from_bits(0x400f1bbd);
+
+    /// Euler's number, the base of the natural logarithm.  
+    /// Exact float representation: 2.71828174591064453125
+    pub const E: f32 = f32::from_bits(0x402df854);
+
+    /// The base two logarithm of e.  
+    /// Exact float representation: 1.44269502162933349609375
+    pub const LOG2_E: f32 = f32::from_bits(0x3fb8aa3b);
+
+    /// The base ten logarithm of e.  
+    /// Exact float representation: 0.4342944920063018798828125
+    pub const LOG10_E: f32 = f32::from_bits(0x3ede5bd9);
+
+    /// The natural logarithm of two.  
+    /// Exact float representation: 0.693147182464599609375
+    pub const LN_2: f32 = f32::from_bits(0x3f317218);
+
+    /// The natural logarithm of ten.  
+    /// Exact float representation: 2.302585124969482421875
+    pub const LN_10: f32 = f32::from_bits(0x40135d8e);
+
+    /// Archimedes' constant, the ratio of a circle's circumference to its diameter.  
+    /// Exact float representation: 3.1415927410125732421875
+    pub const PI: f32 = f32::from_bits(0x40490fdb);
+
+    /// The full circle constant, equal to two times pi.  
+    /// Exact float representation: 6.283185482025146484375
+    pub const TAU: f32 = f32::from_bits(0x40c90fdb);
+
+    /// Pi divided by two  
+    /// Exact float representation: 1.57079637050628662109375
+    pub const FRAC_PI_2: f32 = f32::from_bits(0x3fc90fdb);
+
+    /// Pi divided by three  
+    /// Exact float representation: 1.0471975803375244140625
+    pub const FRAC_PI_3: f32 = f32::from_bits(0x3f860a92);
+
+    /// Pi divided by four  
+    /// Exact float representation: 0.785398185253143310546875
+    pub const FRAC_PI_4: f32 = f32::from_bits(0x3f490fdb);
+
+    /// Pi divided by six  
+    /// Exact float representation: 0.52359879016876220703125
+    pub const FRAC_PI_6: f32 = f32::from_bits(0x3f060a92);
+
+    /// Pi divided by eight  
+    /// Exact float representation: 0.3926990926265716552734375
+    pub const FRAC_PI_8: f32 = f32::from_bits(0x3ec90fdb);
+
+    /// One divided by pi  
+    /// Exact float representation: 0.3183098733425140380859375
+    pub const FRAC_1_PI: f32 = f32::from_bits(0x3ea2f983);
+
+    /// Two divided by pi  
+    /// Exact float representation: 0.636619746685028076171875
+    pub const FRAC_2_PI: f32 = f32::from_bits(0x3f22f983);
+
+    /// Two divided by the square root of pi  
+    /// Exact float representation: 1.12837922573089599609375
+    pub const FRAC_2_SQRT_PI: f32 = f32::from_bits(0x3f906ebb);
+
+    /// The square root of two  
+    /// Exact float representation: 1.41421353816986083984375
+    pub const SQRT_2: f32 = f32::from_bits(0x3fb504f3);
+
+    /// The square root of three  
+    /// Exact float representation: 1.73205077648162841796875
+    pub const SQRT_3: f32 = f32::from_bits(0x3fddb3d7);
+
+    /// One divided by the square root of two, equal to half the square root of two  
+    /// Exact float representation: 0.707106769084930419921875
+    pub const FRAC_1_SQRT_2: f32 = f32::from_bits(0x3f3504f3);
+
+    /// The square root of three divided by two  
+    /// Exact float representation: 0.866025388240814208984375
+    pub const FRAC_SQRT_3_2: f32 = f32::from_bits(0x3f5db3d7);
+
+    /// The Euler-Mascheroni constant gamma.
+    /// The limit of the harmonic series 1 + 1/2 + ... + 1/n minus ln(n) as n goes to infinity.  
+    /// Exact float representation: 0.577215671539306640625
+    pub const EULER_MASCHERONI: f32 = f32::from_bits(0x3f13c468);
+
+    /// Catalan's constant G.
+    /// The sum of the alternating series 1 - 1/3^2 + 1/5^2 - 1/7^2 + ...  
+    /// Exact float representation: 0.915965616703033447265625
+    pub const CATALAN: f32 = f32::from_bits(0x3f6a7cb9);
+
+    /// Apery's constant zeta(3).
+    /// The sum of the series 1 + 1/2^3 + 1/3^3 + 1/4^3 + ...  
+    /// Exact float representation: 1.202056884765625
+    pub const APERY: f32 = f32::from_bits(0x3f99dd00);
+
     /// One over 2 to the 53th power. Equivalent to 1.0 / (1u64 << 53) as f32.  
     /// Exact float representation: 1.1102230246251565404236316680908203125E-16
     pub const INV_2POW53: f32 = f32::from_bits(0x25000000);
@@ -98,4 +436,476 @@ pub mod float {
     /// One over 2 to the 24th power. Equivalent to 1.0 / (1u64 << 24) as f32.  
     /// Exact float representation: 5.9604644775390625E-8
     pub const INV_2POW24: f32 = f32::from_bits(0x33800000);
+
+    /// One over 2 to the 8th power. Equivalent to 1.0 / (1u64 << 8) as f32.  
+    /// Exact float representation: 0.00390625
+    pub const INV_2POW8: f32 = f32::from_bits(0x3b800000);
+
+    /// One over 2 to the 16th power. Equivalent to 1.0 / (1u64 << 16) as f32.  
+    /// Exact float representation: 1.52587890625E-5
+    pub const INV_2POW16: f32 = f32::from_bits(0x37800000);
+
+    /// One over 2 to the 32th power. Equivalent to 1.0 / (1u64 << 32) as f32.  
+    /// Exact float representation: 2.3283064365386962890625E-10
+    pub const INV_2POW32: f32 = f32::from_bits(0x2f800000);
+
+    /// One over 2 to the 63th power. Equivalent to 1.0 / (1u64 << 63) as f32.  
+    /// Exact float representation: 1.08420217248550443400745280086994171142578125E-19
+    pub const INV_2POW63: f32 = f32::from_bits(0x20000000);
+
+    /// One over 2 to the 64th power. Equivalent to 1.0 / (1u128 << 64) as f32.  
+    /// Not zero, 2^-64 is well within the range of normal float values.  
+    /// Exact float representation: 5.42101086242752217003726400434970855712890625E-20
+    pub const INV_2POW64: f32 = f32::from_bits(0x1f800000);
+}
+
+/// Integer constants.
+pub mod integer {
+    /// 2^64 divided by the golden ratio, rounded to the nearest odd integer.  
+    /// Commonly used as the increment of Weyl sequences and in multiplicative hashing,
+    /// since its multiples are spread evenly over all u64.
+    pub const GOLDEN_RATIO_64: u64 = 0x9e3779b97f4a7c15;
+}
+
+/// IEEE 754 bit patterns and field masks,
+/// for manipulating floating point values through their integer representation.
+pub mod bit_patterns {
+    /// Bit pattern of positive infinity in double precision.
+    pub const DOUBLE_POSITIVE_INF: u64 = 0x7ff0000000000000;
+    /// Bit pattern of negative infinity in double precision.
+    pub const DOUBLE_NEGATIVE_INF: u64 = 0xfff0000000000000;
+    /// Bit pattern of the canonical quiet NaN in double precision.
+    pub const DOUBLE_NAN_QUIET: u64 = 0x7ff8000000000000;
+    /// Mask of the sign bit in double precision.
+    pub const DOUBLE_SIGN_MASK: u64 = 0x8000000000000000;
+    /// Mask of the 11 exponent bits in double precision.
+    pub const DOUBLE_EXPONENT_MASK: u64 = 0x7ff0000000000000;
+    /// Mask of the 52 explicit mantissa bits in double precision.
+    pub const DOUBLE_MANTISSA_MASK: u64 = 0x000fffffffffffff;
+    /// Exponent bias in double precision. The stored exponent of 1.0 is equal to the bias.
+    pub const DOUBLE_EXPONENT_BIAS: i32 = 1023;
+
+    /// Bit pattern of positive infinity in single precision.
+    pub const FLOAT_POSITIVE_INF: u32 = 0x7f800000;
+    /// Bit pattern of negative infinity in single precision.
+    pub const FLOAT_NEGATIVE_INF: u32 = 0xff800000;
+    /// Bit pattern of the canonical quiet NaN in single precision.
+    pub const FLOAT_NAN_QUIET: u32 = 0x7fc00000;
+    /// Mask of the sign bit in single precision.
+    pub const FLOAT_SIGN_MASK: u32 = 0x80000000;
+    /// Mask of the 8 exponent bits in single precision.
+    pub const FLOAT_EXPONENT_MASK: u32 = 0x7f800000;
+    /// Mask of the 23 explicit mantissa bits in single precision.
+    pub const FLOAT_MANTISSA_MASK: u32 = 0x007fffff;
+    /// Exponent bias in single precision. The stored exponent of 1.0 is equal to the bias.
+    pub const FLOAT_EXPONENT_BIAS: i32 = 127;
+}
+
+/// Returns the n-th metallic ratio, the positive solution of the equation x^2 = nx + 1.  
+/// Equal to (n + sqrt(n^2 + 4)) / 2, n = 1 gives the golden ratio and n = 2 the silver ratio.  
+/// The square root is computed with Newton-Raphson iterations, so the result can differ
+/// from the correctly rounded value by one ULP.
+pub const fn metallic_ratio_f64(n: u32) -> f64 {
+    let n = n as f64;
+    let y = n * n + 4.0;
+    // Halving the biased exponent approximates the square root to within 6%.
+    const BIAS_HALF: u64 = (bit_patterns::DOUBLE_EXPONENT_BIAS as u64) << 51;
+    let mut root = f64::from_bits((y.to_bits() >> 1) + BIAS_HALF);
+    // The relative error squares with each iteration, five are enough for full precision.
+    let mut i = 0;
+    while i < 5 {
+        root = 0.5 * (root + y / root);
+        i += 1;
+    }
+    0.5 * (n + root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Define a test that checks a constant against its documented exact representations
+    /// and that it is the closest value to the reference digits.
+    macro_rules! test_constant {
+        ($name:ident, $testname:ident, $reference:expr, $exact_double:expr, $exact_float:expr) => {
+            #[test]
+            fn $testname() {
+                assert_eq!(
+                    double::$name.to_bits(),
+                    $exact_double.parse::<f64>().unwrap().to_bits()
+                );
+                assert_eq!(
+                    double::$name.to_bits(),
+                    $reference.parse::<f64>().unwrap().to_bits()
+                );
+                assert_eq!(
+                    float::$name.to_bits(),
+                    $exact_float.parse::<f32>().unwrap().to_bits()
+                );
+                assert_eq!(
+                    float::$name.to_bits(),
+                    $reference.parse::<f32>().unwrap().to_bits()
+                );
+            }
+        };
+    }
+
+    test_constant!(
+        GOLDEN_RATIO,
+        golden_ratio_test,
+        "1.61803398874989484820458683436563811772030917980576286213544862270526046282",
+        "1.6180339887498949025257388711906969547271728515625",
+        "1.61803400516510009765625"
+    );
+    test_constant!(
+        GOLDEN_RATIO_CONJUGATE,
+        golden_ratio_conjugate_test,
+        "0.61803398874989484820458683436563811772030917980576286213544862270526046282",
+        "0.6180339887498949025257388711906969547271728515625",
+        "0.61803400516510009765625"
+    );
+    test_constant!(
+        SQRT_5,
+        sqrt_5_test,
+        "2.23606797749978969640917366873127623544061835961152572427089724541052092564",
+        "2.236067977499789805051477742381393909454345703125",
+        "2.2360680103302001953125"
+    );
+    test_constant!(
+        E,
+        e_test,
+        "2.71828182845904523536028747135266249775724709369995957496696762772407663035",
+        "2.718281828459045090795598298427648842334747314453125",
+        "2.71828174591064453125"
+    );
+    test_constant!(
+        LOG2_E,
+        log2_e_test,
+        "1.44269504088896340735992468100189213742664595415298593413544940693110921918",
+        "1.442695040888963387004650940070860087871551513671875",
+        "1.44269502162933349609375"
+    );
+    test_constant!(
+        LOG10_E,
+        log10_e_test,
+        "0.434294481903251827651128918916605082294397005803666566114453783165864649209",
+        "0.43429448190325181666793241674895398318767547607421875",
+        "0.4342944920063018798828125"
+    );
+    test_constant!(
+        LN_2,
+        ln_2_test,
+        "0.693147180559945309417232121458176568075500134360255254120680009493393621970",
+        "0.69314718055994528622676398299518041312694549560546875",
+        "0.693147182464599609375"
+    );
+    test_constant!(
+        LN_10,
+        ln_10_test,
+        "2.30258509299404568401799145468436420760110148862877297603332790096757260968",
+        "2.30258509299404590109361379290930926799774169921875",
+        "2.302585124969482421875"
+    );
+    test_constant!(
+        PI,
+        pi_test,
+        "3.14159265358979323846264338327950288419716939937510582097494459230781640629",
+        "3.141592653589793115997963468544185161590576171875",
+        "3.1415927410125732421875"
+    );
+    test_constant!(
+        TAU,
+        tau_test,
+        "6.28318530717958647692528676655900576839433879875021164194988918461563281257",
+        "6.28318530717958623199592693708837032318115234375",
+        "6.283185482025146484375"
+    );
+    test_constant!(
+        FRAC_PI_2,
+        frac_pi_2_test,
+        "1.57079632679489661923132169163975144209858469968755291048747229615390820314",
+        "1.5707963267948965579989817342720925807952880859375",
+        "1.57079637050628662109375"
+    );
+    test_constant!(
+        FRAC_PI_3,
+        frac_pi_3_test,
+        "1.04719755119659774615421446109316762806572313312503527365831486410260546876",
+        "1.047197551196597853362391106202267110347747802734375",
+        "1.0471975803375244140625"
+    );
+    test_constant!(
+        FRAC_PI_4,
+        frac_pi_4_test,
+        "0.785398163397448309615660845819875721049292349843776455243736148076954101572",
+        "0.78539816339744827899949086713604629039764404296875",
+        "0.785398185253143310546875"
+    );
+    test_constant!(
+        FRAC_PI_6,
+        frac_pi_6_test,
+        "0.523598775598298873077107230546583814032861566562517636829157432051302734381",
+        "0.5235987755982989266811955531011335551738739013671875",
+        "0.52359879016876220703125"
+    );
+    test_constant!(
+        FRAC_PI_8,
+        frac_pi_8_test,
+        "0.392699081698724154807830422909937860524646174921888227621868074038477050786",
+        "0.392699081698724139499745433568023145198822021484375",
+        "0.3926990926265716552734375"
+    );
+    test_constant!(
+        FRAC_1_PI,
+        frac_1_pi_test,
+        "0.318309886183790671537767526745028724068919291480912897495334688117793595268",
+        "0.318309886183790691216444201927515678107738494873046875",
+        "0.3183098733425140380859375"
+    );
+    test_constant!(
+        FRAC_2_PI,
+        frac_2_pi_test,
+        "0.636619772367581343075535053490057448137838582961825794990669376235587190537",
+        "0.63661977236758138243288840385503135621547698974609375",
+        "0.636619746685028076171875"
+    );
+    test_constant!(
+        FRAC_2_SQRT_PI,
+        frac_2_sqrt_pi_test,
+        "1.12837916709551257389615890312154517168810125865799771368817144342128493688",
+        "1.1283791670955125585606992899556644260883331298828125",
+        "1.12837922573089599609375"
+    );
+    test_constant!(
+        SQRT_2,
+        sqrt_2_test,
+        "1.41421356237309504880168872420969807856967187537694807317667973799073247846",
+        "1.4142135623730951454746218587388284504413604736328125",
+        "1.41421353816986083984375"
+    );
+    test_constant!(
+        SQRT_3,
+        sqrt_3_test,
+        "1.73205080756887729352744634150587236694280525381038062805580697945193301691",
+        "1.732050807568877193176604123436845839023590087890625",
+        "1.73205077648162841796875"
+    );
+    test_constant!(
+        FRAC_1_SQRT_2,
+        frac_1_sqrt_2_test,
+        "0.707106781186547524400844362104849039284835937688474036588339868995366239231",
+        "0.70710678118654757273731092936941422522068023681640625",
+        "0.707106769084930419921875"
+    );
+    test_constant!(
+        FRAC_SQRT_3_2,
+        frac_sqrt_3_2_test,
+        "0.866025403784438646763723170752936183471402626905190314027903489725966508454",
+        "0.8660254037844385965883020617184229195117950439453125",
+        "0.866025388240814208984375"
+    );
+    test_constant!(
+        EULER_MASCHERONI,
+        euler_mascheroni_test,
+        "0.577215664901532860606512090082402431042159335939923598805767234884867726778",
+        "0.57721566490153286554942724251304753124713897705078125",
+        "0.577215671539306640625"
+    );
+    test_constant!(
+        CATALAN,
+        catalan_test,
+        "0.915965594177219015054603514932384110774149374281672134266498119621763019776",
+        "0.91596559417721901130704509341740049421787261962890625",
+        "0.915965616703033447265625"
+    );
+    test_constant!(
+        APERY,
+        apery_test,
+        "1.20205690315959428539973816151144999076498629234049888179227155534183820579",
+        "1.2020569031595942366408280577161349356174468994140625",
+        "1.202056884765625"
+    );
+    test_constant!(
+        PLASTIC_NUMBER,
+        plastic_number_test,
+        "1.32471795724474602596090885447809734073440405690173336453401505030282785125",
+        "1.3247179572447460582651501681539230048656463623046875",
+        "1.324717998504638671875"
+    );
+
+    /// Define a test that checks constants against the equivalent constants in core.
+    macro_rules! test_core_constants {
+        ($testname:ident, $($name:ident),*) => {
+            #[test]
+            fn $testname() {
+                $(
+                    assert_eq!(
+                        double::$name.to_bits(),
+                        core::f64::consts::$name.to_bits(),
+                        "Failed with {}",
+                        stringify!($name)
+                    );
+                    assert_eq!(
+                        float::$name.to_bits(),
+                        core::f32::consts::$name.to_bits(),
+                        "Failed with {}",
+                        stringify!($name)
+                    );
+                )*
+            }
+        };
+    }
+
+    test_core_constants!(logarithm_core_test, E, LOG2_E, LOG10_E, LN_2, LN_10);
+    test_core_constants!(sqrt_core_test, SQRT_2, FRAC_1_SQRT_2);
+    test_core_constants!(
+        pi_core_test,
+        PI,
+        TAU,
+        FRAC_PI_2,
+        FRAC_PI_3,
+        FRAC_PI_4,
+        FRAC_PI_6,
+        FRAC_PI_8,
+        FRAC_1_PI,
+        FRAC_2_PI,
+        FRAC_2_SQRT_PI
+    );
+
+    /// Test that the inverse powers of two scale the power of two to exactly one.
+    #[test]
+    fn inverse_power_of_two_test() {
+        assert_eq!(double::INV_2POW8 * (1u64 << 8) as f64, 1.0);
+        assert_eq!(double::INV_2POW16 * (1u64 << 16) as f64, 1.0);
+        assert_eq!(double::INV_2POW24 * (1u64 << 24) as f64, 1.0);
+        assert_eq!(double::INV_2POW32 * (1u64 << 32) as f64, 1.0);
+        assert_eq!(double::INV_2POW53 * (1u64 << 53) as f64, 1.0);
+        assert_eq!(double::INV_2POW63 * (1u64 << 63) as f64, 1.0);
+        assert_eq!(double::INV_2POW64 * (1u128 << 64) as f64, 1.0);
+
+        assert_eq!(float::INV_2POW8 * (1u64 << 8) as f32, 1.0);
+        assert_eq!(float::INV_2POW16 * (1u64 << 16) as f32, 1.0);
+        assert_eq!(float::INV_2POW24 * (1u64 << 24) as f32, 1.0);
+        assert_eq!(float::INV_2POW32 * (1u64 << 32) as f32, 1.0);
+        assert_eq!(float::INV_2POW53 * (1u64 << 53) as f32, 1.0);
+        assert_eq!(float::INV_2POW63 * (1u64 << 63) as f32, 1.0);
+        assert_eq!(float::INV_2POW64 * (1u128 << 64) as f32, 1.0);
+
+        // Scaling the largest integer sample stays below one.
+        assert!((u32::MAX as f64) * double::INV_2POW32 < 1.0);
+        assert!(((u64::MAX >> 11) as f64) * double::INV_2POW53 < 1.0);
+        assert!((u8::MAX as f32) * float::INV_2POW8 < 1.0);
+        assert!((u16::MAX as f32) * float::INV_2POW16 < 1.0);
+    }
+
+    /// Test that the bit patterns produce the expected values.
+    #[test]
+    fn bit_patterns_double_test() {
+        use bit_patterns::*;
+        assert_eq!(f64::from_bits(DOUBLE_POSITIVE_INF), f64::INFINITY);
+        assert_eq!(f64::from_bits(DOUBLE_NEGATIVE_INF), f64::NEG_INFINITY);
+        assert!(f64::from_bits(DOUBLE_NAN_QUIET).is_nan());
+        assert_eq!(f64::NAN.to_bits(), DOUBLE_NAN_QUIET);
+        assert_eq!(
+            f64::from_bits(DOUBLE_SIGN_MASK).to_bits(),
+            (-0.0f64).to_bits()
+        );
+        assert_eq!(
+            f64::from_bits(DOUBLE_MANTISSA_MASK),
+            f64::from_bits(0x000fffffffffffff)
+        );
+        assert!(f64::from_bits(DOUBLE_MANTISSA_MASK).is_subnormal());
+
+        // The masks partition the bits.
+        assert_eq!(
+            DOUBLE_SIGN_MASK | DOUBLE_EXPONENT_MASK | DOUBLE_MANTISSA_MASK,
+            u64::MAX
+        );
+        assert_eq!(DOUBLE_SIGN_MASK & DOUBLE_EXPONENT_MASK, 0);
+        assert_eq!(DOUBLE_EXPONENT_MASK & DOUBLE_MANTISSA_MASK, 0);
+
+        // Decompose 1.5 = 2^0 * 1.1b
+        let bits = 1.5f64.to_bits();
+        assert_eq!(bits & DOUBLE_SIGN_MASK, 0);
+        assert_eq!(
+            ((bits & DOUBLE_EXPONENT_MASK) >> 52) as i32 - DOUBLE_EXPONENT_BIAS,
+            0
+        );
+        assert_eq!(bits & DOUBLE_MANTISSA_MASK, 1 << 51);
+        let exponent = ((DOUBLE_EXPONENT_BIAS + 10) as u64) << 52;
+        assert_eq!(f64::from_bits(exponent), 1024.0);
+    }
+
+    /// Test that the bit patterns produce the expected values.
+    #[test]
+    fn bit_patterns_float_test() {
+        use bit_patterns::*;
+        assert_eq!(f32::from_bits(FLOAT_POSITIVE_INF), f32::INFINITY);
+        assert_eq!(f32::from_bits(FLOAT_NEGATIVE_INF), f32::NEG_INFINITY);
+        assert!(f32::from_bits(FLOAT_NAN_QUIET).is_nan());
+        assert_eq!(f32::NAN.to_bits(), FLOAT_NAN_QUIET);
+        assert_eq!(
+            f32::from_bits(FLOAT_SIGN_MASK).to_bits(),
+            (-0.0f32).to_bits()
+        );
+        assert!(f32::from_bits(FLOAT_MANTISSA_MASK).is_subnormal());
+
+        // The masks partition the bits.
+        assert_eq!(
+            FLOAT_SIGN_MASK | FLOAT_EXPONENT_MASK | FLOAT_MANTISSA_MASK,
+            u32::MAX
+        );
+        assert_eq!(FLOAT_SIGN_MASK & FLOAT_EXPONENT_MASK, 0);
+        assert_eq!(FLOAT_EXPONENT_MASK & FLOAT_MANTISSA_MASK, 0);
+
+        // Decompose -0.75 = -2^-1 * 1.1b
+        let bits = (-0.75f32).to_bits();
+        assert_eq!(bits & FLOAT_SIGN_MASK, FLOAT_SIGN_MASK);
+        assert_eq!(
+            ((bits & FLOAT_EXPONENT_MASK) >> 23) as i32 - FLOAT_EXPONENT_BIAS,
+            -1
+        );
+        assert_eq!(bits & FLOAT_MANTISSA_MASK, 1 << 22);
+        let exponent = ((FLOAT_EXPONENT_BIAS + 10) as u32) << 23;
+        assert_eq!(f32::from_bits(exponent), 1024.0);
+    }
+
+    /// Test that the integer golden ratio is odd and approximates 2^64 / phi.
+    #[test]
+    fn golden_ratio_64_test() {
+        assert_eq!(integer::GOLDEN_RATIO_64 & 1, 1);
+        assert_eq!(
+            integer::GOLDEN_RATIO_64 as f64,
+            18446744073709551616.0 * double::GOLDEN_RATIO_CONJUGATE
+        );
+    }
+
+    // Compile time test to ensure the metallic ratio function remains const.
+    const _: () = assert!(metallic_ratio_f64(1) == double::GOLDEN_RATIO);
+
+    /// Compare the metallic ratios with the hardcoded constants and the std square root.
+    #[test]
+    fn metallic_ratio_test() {
+        assert_eq!(
+            metallic_ratio_f64(1).to_bits(),
+            double::GOLDEN_RATIO.to_bits()
+        );
+        assert_eq!(
+            metallic_ratio_f64(2).to_bits(),
+            double::SQRT_2_PLUS_1.to_bits()
+        );
+        // Bronze ratio (3 + sqrt(13)) / 2
+        assert_eq!(metallic_ratio_f64(3), 3.302775637731995);
+        assert_eq!(metallic_ratio_f64(0), 1.0);
+        for n in (0..1000).chain([u16::MAX as u32, u32::MAX]) {
+            let x = metallic_ratio_f64(n);
+            let n = n as f64;
+            let expected = (n + (n * n + 4.0).sqrt()) / 2.0;
+            assert!(
+                (x - expected).abs() <= expected * f64::EPSILON,
+                "Failed with n={}",
+                n
+            );
+        }
+    }
 }
@@ -13,10 +13,21 @@
 //! ## Description
 //! Bit level math functions, also includes mathematical constants.
 //! Optimized for size and performance. Only uses rust core, no std library or other dependencies.
+//! Requires Rust 1.86 or newer.
+//!
+//! ## Features
+//! - `libm`: Enables functions that need floating point math not available in core,
+//!   like gaussian sampling. Adds a dependency on the `libm` crate.
+//! - `rand_core`: Implements `RngCore` and `SeedableRng` for `Lehmer64`,
+//!   so it can be used with the `rand` ecosystem. Adds a dependency on the `rand_core` crate.
 //!
 //! ## Examples
 //! ```
-//! use fastmath::{log, sign, consts, rng};
+//! use fastmath::{bits, log, pow, sign, consts, rng};
+//!
+//! // Bit manipulation examples
+//! assert_eq!(bits::set_bit_u32(0, 4), 16);
+//! assert!(bits::test_bit_u8(0b1000_0000, 7));
 //!
 //! // Log examples
 //! // Equivalent to 2**63 - 1
@@ -24,6 +35,9 @@
 //! let testval: u64 = (1 << 63) - 1;
 //! assert_eq!(log::u64_log2_floor(testval), 62);
 //!
+//! // Integer root examples
+//! assert_eq!(pow::isqrt_u64(testval), 3037000499);
+//!
 //! // Sign examples
 //! assert_eq!(sign::int_sign_isize(isize::MIN), -1);
 //! assert_eq!(sign::int_sign_i64(0), 1);
@@ -40,8 +54,10 @@
 #![warn(missing_docs, missing_debug_implementations, rust_2018_idioms)]
 #![no_std]
 
+pub mod bits;
 pub mod consts;
 pub mod log;
+pub mod pow;
 pub mod rng;
 pub mod sign;
 pub mod traits;
@@ -24,12 +24,14 @@
 //! assert_eq!(testval.ilog2(), 62u32);
 //! ```
 
+use crate::traits::BaseInt;
+
 /// Define a function for supplied datatype that is equivalent to floor(log2(x)).
 macro_rules! generic_log2_floor {
     ($fnname:ident, $datatype:ty) => {
         /// Equivalent to floor(log2(x))
         /// Returns `u32::MAX` if x is zero.
-        pub fn $fnname(x: $datatype) -> u32 {
+        pub const fn $fnname(x: $datatype) -> u32 {
             (<$datatype>::BITS - x.leading_zeros()).wrapping_sub(1)
         }
     };
@@ -42,10 +44,331 @@ generic_log2_floor!(u64_log2_floor, u64);
 generic_log2_floor!(u128_log2_floor, u128);
 generic_log2_floor!(usize_log2_floor, usize);
 
+/// Equivalent to floor(log2(x)) for any primitive integer.
+/// Returns `u32::MAX` if x is zero.
+/// Negative inputs are treated as their unsigned bit pattern and return `T::BITS - 1`.
+#[inline]
+pub fn log2_floor<T: BaseInt>(x: T) -> u32 {
+    (T::BITS - x.leading_zeros()).wrapping_sub(1)
+}
+
+/// Equivalent to floor(log_base(x)), computed by repeated multiplication.
+/// Returns `u32::MAX` if x is zero.
+///
+/// # Panics
+/// Panics if base is smaller than 2.
+pub fn u64_log_floor(x: u64, base: u64) -> u32 {
+    assert!(base >= 2, "Logarithm base must be at least 2");
+    if x == 0 {
+        return u32::MAX;
+    }
+    // Counts the powers of base that are at most x.
+    let mut power = base;
+    let mut result = 0;
+    while power <= x {
+        result += 1;
+        match power.checked_mul(base) {
+            Some(next) => power = next,
+            // The next power exceeds u64::MAX and therefore x.
+            None => break,
+        }
+    }
+    result
+}
+
+/// Equivalent to floor(log_base(x)), computed by repeated multiplication.
+/// Returns `None` if x is zero.
+///
+/// # Panics
+/// Panics if base is smaller than 2.
+pub fn u64_log_floor_checked(x: u64, base: u64) -> Option<u32> {
+    assert!(base >= 2, "Logarithm base must be at least 2");
+    if x == 0 {
+        None
+    } else {
+        Some(u64_log_floor(x, base))
+    }
+}
+
+/// Define a function for supplied datatype that is equivalent to floor(log2(x))
+/// but returns `None` instead of a sentinel value if x is zero.
+macro_rules! generic_log2_floor_checked {
+    ($fnname:ident, $floorfn:ident, $datatype:ty) => {
+        /// Equivalent to floor(log2(x))
+        /// Returns `None` if x is zero.
+        pub const fn $fnname(x: $datatype) -> Option<u32> {
+            if x == 0 {
+                None
+            } else {
+                Some($floorfn(x))
+            }
+        }
+    };
+}
+
+generic_log2_floor_checked!(u8_log2_floor_checked, u8_log2_floor, u8);
+generic_log2_floor_checked!(u16_log2_floor_checked, u16_log2_floor, u16);
+generic_log2_floor_checked!(u32_log2_floor_checked, u32_log2_floor, u32);
+generic_log2_floor_checked!(u64_log2_floor_checked, u64_log2_floor, u64);
+generic_log2_floor_checked!(u128_log2_floor_checked, u128_log2_floor, u128);
+generic_log2_floor_checked!(usize_log2_floor_checked, usize_log2_floor, usize);
+
+/// Define a function for supplied signed datatype that is equivalent to floor(log2(x)).
+macro_rules! generic_signed_log2_floor {
+    ($fnname:ident, $floorfn:ident, $datatype:ty, $unsigned:ty) => {
+        /// Equivalent to floor(log2(x))
+        /// Returns `None` if x is zero or negative.
+        pub const fn $fnname(x: $datatype) -> Option<u32> {
+            if x <= 0 {
+                None
+            } else {
+                Some($floorfn(x as $unsigned))
+            }
+        }
+    };
+}
+
+generic_signed_log2_floor!(i8_log2_floor, u8_log2_floor, i8, u8);
+generic_signed_log2_floor!(i16_log2_floor, u16_log2_floor, i16, u16);
+generic_signed_log2_floor!(i32_log2_floor, u32_log2_floor, i32, u32);
+generic_signed_log2_floor!(i64_log2_floor, u64_log2_floor, i64, u64);
+generic_signed_log2_floor!(i128_log2_floor, u128_log2_floor, i128, u128);
+generic_signed_log2_floor!(isize_log2_floor, usize_log2_floor, isize, usize);
+
+/// Define a function for supplied datatype that is equivalent to ceil(log2(x)).
+macro_rules! generic_log2_ceil {
+    ($fnname:ident, $datatype:ty) => {
+        /// Equivalent to ceil(log2(x))
+        /// Returns `u32::MAX` if x is zero.
+        pub const fn $fnname(x: $datatype) -> u32 {
+            // x - 1 has its highest set bit one position below the result,
+            // for x = 1 it has no bits set and the result is zero.
+            // For x = 0 the subtraction wraps, the result is replaced by the sentinel.
+            (<$datatype>::BITS - x.wrapping_sub(1).leading_zeros())
+                | ((x == 0) as u32).wrapping_neg()
+        }
+    };
+}
+
+generic_log2_ceil!(u8_log2_ceil, u8);
+generic_log2_ceil!(u16_log2_ceil, u16);
+generic_log2_ceil!(u32_log2_ceil, u32);
+generic_log2_ceil!(u64_log2_ceil, u64);
+generic_log2_ceil!(u128_log2_ceil, u128);
+generic_log2_ceil!(usize_log2_ceil, usize);
+
+/// Define a function for supplied datatype that returns the number of significant bits.
+macro_rules! generic_bit_width {
+    ($fnname:ident, $datatype:ty) => {
+        /// Returns the number of significant bits, equivalent to floor(log2(x)) + 1.
+        /// Returns 0 if x is zero.
+        #[inline]
+        pub const fn $fnname(x: $datatype) -> u32 {
+            <$datatype>::BITS - x.leading_zeros()
+        }
+    };
+}
+
+generic_bit_width!(u8_bit_width, u8);
+generic_bit_width!(u16_bit_width, u16);
+generic_bit_width!(u32_bit_width, u32);
+generic_bit_width!(u64_bit_width, u64);
+generic_bit_width!(u128_bit_width, u128);
+generic_bit_width!(usize_bit_width, usize);
+
+/// Define a function for supplied datatype that checks if x is a power of two.
+macro_rules! generic_is_power_of_two {
+    ($fnname:ident, $datatype:ty) => {
+        /// Returns true if x is a power of two.
+        /// Returns false if x is zero.
+        #[inline]
+        pub fn $fnname(x: $datatype) -> bool {
+            (x != 0) & (x & x.wrapping_sub(1) == 0)
+        }
+    };
+}
+
+generic_is_power_of_two!(is_power_of_two_u8, u8);
+generic_is_power_of_two!(is_power_of_two_u16, u16);
+generic_is_power_of_two!(is_power_of_two_u32, u32);
+generic_is_power_of_two!(is_power_of_two_u64, u64);
+generic_is_power_of_two!(is_power_of_two_u128, u128);
+generic_is_power_of_two!(is_power_of_two_usize, usize);
+
+/// Returns true if x is a power of two.
+/// Returns false if x is zero or negative.
+#[inline]
+pub fn is_power_of_two<T: BaseInt>(x: T) -> bool {
+    // Checking the sign first avoids the overflow of x - 1 for zero and the minimum of signed types.
+    x > T::ZERO && x & (x - T::ONE) == T::ZERO
+}
+
+/// Define functions for supplied datatype that return the smallest power of two
+/// greater than or equal to x.
+macro_rules! generic_next_power_of_two {
+    ($fnname:ident, $checked_fnname:ident, $floorfn:ident, $datatype:ty) => {
+        /// Returns the smallest power of two greater than or equal to x.
+        /// Returns 1 if x is zero.
+        /// Returns 0 if the result is not representable (x > 2^(BITS - 1)).
+        /// Unlike the standard library `next_power_of_two`, this never panics.
+        #[inline]
+        pub fn $fnname(x: $datatype) -> $datatype {
+            if x <= 1 {
+                return 1;
+            }
+            // All bits up to and including the highest set bit of x - 1.
+            let mask = <$datatype>::MAX >> (<$datatype>::BITS - 1 - $floorfn(x - 1));
+            // The mask is all ones if the result overflows, so this wraps to zero.
+            mask.wrapping_add(1)
+        }
+
+        /// Returns the smallest power of two greater than or equal to x.
+        /// Returns 1 if x is zero.
+        /// Returns `None` if the result is not representable (x > 2^(BITS - 1)).
+        #[inline]
+        pub fn $checked_fnname(x: $datatype) -> Option<$datatype> {
+            if x <= 1 {
+                return Some(1);
+            }
+            let mask = <$datatype>::MAX >> (<$datatype>::BITS - 1 - $floorfn(x - 1));
+            mask.checked_add(1)
+        }
+    };
+}
+
+generic_next_power_of_two!(
+    u8_next_power_of_two,
+    u8_next_power_of_two_checked,
+    u8_log2_floor,
+    u8
+);
+generic_next_power_of_two!(
+    u16_next_power_of_two,
+    u16_next_power_of_two_checked,
+    u16_log2_floor,
+    u16
+);
+generic_next_power_of_two!(
+    u32_next_power_of_two,
+    u32_next_power_of_two_checked,
+    u32_log2_floor,
+    u32
+);
+generic_next_power_of_two!(
+    u64_next_power_of_two,
+    u64_next_power_of_two_checked,
+    u64_log2_floor,
+    u64
+);
+generic_next_power_of_two!(
+    u128_next_power_of_two,
+    u128_next_power_of_two_checked,
+    u128_log2_floor,
+    u128
+);
+generic_next_power_of_two!(
+    usize_next_power_of_two,
+    usize_next_power_of_two_checked,
+    usize_log2_floor,
+    usize
+);
+
+/// Define a function for supplied datatype that returns the largest power of two
+/// less than or equal to x.
+macro_rules! generic_prev_power_of_two {
+    ($fnname:ident, $floorfn:ident, $datatype:ty) => {
+        /// Returns the largest power of two less than or equal to x.
+        /// Returns 0 if x is zero.
+        #[inline]
+        pub fn $fnname(x: $datatype) -> $datatype {
+            // For x = 0 the sentinel shift amount is masked by the wrapping shift,
+            // and the shifted value is zero.
+            ((x != 0) as $datatype).wrapping_shl($floorfn(x))
+        }
+    };
+}
+
+generic_prev_power_of_two!(u8_prev_power_of_two, u8_log2_floor, u8);
+generic_prev_power_of_two!(u16_prev_power_of_two, u16_log2_floor, u16);
+generic_prev_power_of_two!(u32_prev_power_of_two, u32_log2_floor, u32);
+generic_prev_power_of_two!(u64_prev_power_of_two, u64_log2_floor, u64);
+generic_prev_power_of_two!(u128_prev_power_of_two, u128_log2_floor, u128);
+generic_prev_power_of_two!(usize_prev_power_of_two, usize_log2_floor, usize);
+
+/// Define a function for supplied datatype that is equivalent to floor(log10(x)).
+macro_rules! generic_log10_floor {
+    ($fnname:ident, $datatype:ty) => {
+        /// Equivalent to floor(log10(x))
+        /// Returns `u32::MAX` if x is zero.
+        pub fn $fnname(x: $datatype) -> u32 {
+            const POWER_COUNT: usize = <$datatype>::MAX.ilog10() as usize + 1;
+            // All powers of ten representable by the datatype, starting at 10^0.
+            const POWERS_OF_TEN: [$datatype; POWER_COUNT] = {
+                let mut table = [1; POWER_COUNT];
+                let mut i = 1;
+                while i < POWER_COUNT {
+                    table[i] = table[i - 1] * 10;
+                    i += 1;
+                }
+                table
+            };
+            // Count the powers of ten that are smaller or equal to x.
+            // For x = 0 the count is zero and the subtraction wraps to the sentinel.
+            let mut count: u32 = 0;
+            for power in POWERS_OF_TEN {
+                count += (x >= power) as u32;
+            }
+            count.wrapping_sub(1)
+        }
+    };
+}
+
+generic_log10_floor!(u8_log10_floor, u8);
+generic_log10_floor!(u16_log10_floor, u16);
+generic_log10_floor!(u32_log10_floor, u32);
+generic_log10_floor!(u64_log10_floor, u64);
+generic_log10_floor!(u128_log10_floor, u128);
+generic_log10_floor!(usize_log10_floor, usize);
+
 mod tests {
     #[allow(unused_imports)]
     use super::*;
 
+    // Compile time tests to ensure the log2_floor functions remain const.
+    const _: () = assert!(u8_log2_floor(8) == 3);
+    const _: () = assert!(u16_log2_floor(1 << 15) == 15);
+    const _: () = assert!(u32_log2_floor(1024) == 10);
+    const _: () = assert!(u64_log2_floor(8) == 3);
+    const _: () = assert!(u128_log2_floor(u128::MAX) == 127);
+    const _: () = assert!(usize_log2_floor(0) == u32::MAX);
+
+    // The results can size arrays.
+    const _: [u8; u32_log2_floor(1024) as usize] = [0; 10];
+
+    // Compile time tests to ensure the signed log2_floor functions remain const.
+    const _: () = assert!(i8_log2_floor(-1).is_none());
+    const _: () = assert!(matches!(i16_log2_floor(i16::MAX), Some(14)));
+    const _: () = assert!(matches!(i32_log2_floor(1024), Some(10)));
+    const _: () = assert!(i64_log2_floor(0).is_none());
+    const _: () = assert!(matches!(i128_log2_floor(1), Some(0)));
+    const _: () = assert!(matches!(isize_log2_floor(3), Some(1)));
+
+    // Compile time tests to ensure the checked log2_floor functions remain const.
+    const _: () = assert!(u8_log2_floor_checked(0).is_none());
+    const _: () = assert!(matches!(u16_log2_floor_checked(1), Some(0)));
+    const _: () = assert!(matches!(u32_log2_floor_checked(1024), Some(10)));
+    const _: () = assert!(matches!(u64_log2_floor_checked(u64::MAX), Some(63)));
+    const _: () = assert!(u128_log2_floor_checked(0).is_none());
+    const _: () = assert!(matches!(usize_log2_floor_checked(3), Some(1)));
+
+    // Compile time tests to ensure the log2_ceil functions remain const.
+    const _: () = assert!(u8_log2_ceil(9) == 4);
+    const _: () = assert!(u16_log2_ceil(1 << 15) == 15);
+    const _: () = assert!(u32_log2_ceil(1025) == 11);
+    const _: () = assert!(u64_log2_ceil(1) == 0);
+    const _: () = assert!(u128_log2_ceil(u128::MAX) == 128);
+    const _: () = assert!(usize_log2_ceil(0) == u32::MAX);
+
     /// Define a test function to test a log2_floor function.
     macro_rules! test_log2_floor {
         ($datatype:ty, $testfn:expr, $testname:ident) => {
@@ -99,4 +422,737 @@ mod tests {
     test_log2_floor!(u64, u64_log2_floor, u64_log2_floor_test);
     test_log2_floor!(u128, u128_log2_floor, u128_log2_floor_test);
     test_log2_floor!(usize, usize_log2_floor, usize_log2_floor_test);
+
+    /// Define a test function to test a checked log2_floor function against the unchecked one.
+    macro_rules! test_log2_floor_checked {
+        ($datatype:ty, $testfn:expr, $uncheckedfn:expr, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                // Powers of two and the adjacent numbers up to 2**(bits - 1)
+                for exponent in 1..<$datatype>::BITS {
+                    let base_value: $datatype = (1 << exponent);
+                    for x in [base_value - 1, base_value, base_value + 1] {
+                        assert_eq!($testfn(x), Some($uncheckedfn(x)), "Failed with x={}", x);
+                    }
+                }
+                // Special edge cases
+                assert_eq!($testfn(0), None, "Failed with x=0");
+                assert_eq!($testfn(1), Some(0), "Failed with x=1");
+                assert_eq!(
+                    $testfn(<$datatype>::MAX),
+                    Some(<$datatype>::BITS - 1),
+                    "Failed with x=MAXINT"
+                );
+            }
+        };
+    }
+
+    test_log2_floor_checked!(
+        u8,
+        u8_log2_floor_checked,
+        u8_log2_floor,
+        u8_log2_floor_checked_test
+    );
+    test_log2_floor_checked!(
+        u16,
+        u16_log2_floor_checked,
+        u16_log2_floor,
+        u16_log2_floor_checked_test
+    );
+    test_log2_floor_checked!(
+        u32,
+        u32_log2_floor_checked,
+        u32_log2_floor,
+        u32_log2_floor_checked_test
+    );
+    test_log2_floor_checked!(
+        u64,
+        u64_log2_floor_checked,
+        u64_log2_floor,
+        u64_log2_floor_checked_test
+    );
+    test_log2_floor_checked!(
+        u128,
+        u128_log2_floor_checked,
+        u128_log2_floor,
+        u128_log2_floor_checked_test
+    );
+    test_log2_floor_checked!(
+        usize,
+        usize_log2_floor_checked,
+        usize_log2_floor,
+        usize_log2_floor_checked_test
+    );
+
+    /// Define a test function to test a signed log2_floor function.
+    macro_rules! test_signed_log2_floor {
+        ($datatype:ty, $testfn:expr, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                // Powers of two and the adjacent numbers up to 2**(bits - 2)
+                for exponent in 1..<$datatype>::BITS - 1 {
+                    let base_value: $datatype = (1 << exponent);
+                    assert_eq!(
+                        $testfn(base_value - 1),
+                        Some(exponent - 1),
+                        "Failed with x=2^{} - 1",
+                        exponent
+                    );
+                    assert_eq!(
+                        $testfn(base_value),
+                        Some(exponent),
+                        "Failed with x=2^{}",
+                        exponent
+                    );
+                    assert_eq!(
+                        $testfn(base_value + 1),
+                        Some(exponent),
+                        "Failed with x=2^{} + 1",
+                        exponent
+                    );
+                    assert_eq!($testfn(-base_value), None, "Failed with x=-2^{}", exponent);
+                }
+                // Special edge cases
+                assert_eq!($testfn(<$datatype>::MIN), None, "Failed with x=MININT");
+                assert_eq!($testfn(-1), None, "Failed with x=-1");
+                assert_eq!($testfn(0), None, "Failed with x=0");
+                assert_eq!($testfn(1), Some(0), "Failed with x=1");
+                assert_eq!($testfn(2), Some(1), "Failed with x=2");
+                assert_eq!(
+                    $testfn(<$datatype>::MAX),
+                    Some(<$datatype>::BITS - 2),
+                    "Failed with x=MAXINT"
+                );
+            }
+        };
+    }
+
+    test_signed_log2_floor!(i8, i8_log2_floor, i8_log2_floor_test);
+    test_signed_log2_floor!(i16, i16_log2_floor, i16_log2_floor_test);
+    test_signed_log2_floor!(i32, i32_log2_floor, i32_log2_floor_test);
+    test_signed_log2_floor!(i64, i64_log2_floor, i64_log2_floor_test);
+    test_signed_log2_floor!(i128, i128_log2_floor, i128_log2_floor_test);
+    test_signed_log2_floor!(isize, isize_log2_floor, isize_log2_floor_test);
+
+    /// Define a test function to compare the generic log2_floor with a type specific one.
+    macro_rules! test_generic_log2_floor {
+        ($datatype:ty, $specificfn:expr, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                // Powers of two and the adjacent numbers up to the largest positive power
+                for exponent in 1..<$datatype>::BITS - 1 {
+                    let base_value: $datatype = (1 << exponent);
+                    for x in [base_value - 1, base_value, base_value + 1] {
+                        assert_eq!(log2_floor(x), $specificfn(x), "Failed with x={}", x);
+                    }
+                }
+                // Special edge cases
+                assert_eq!(log2_floor(0 as $datatype), u32::MAX, "Failed with x=0");
+                assert_eq!(log2_floor(1 as $datatype), 0, "Failed with x=1");
+                assert_eq!(
+                    log2_floor(<$datatype>::MAX),
+                    $specificfn(<$datatype>::MAX),
+                    "Failed with x=MAXINT"
+                );
+            }
+        };
+    }
+
+    test_generic_log2_floor!(u8, u8_log2_floor, u8_generic_log2_floor_test);
+    test_generic_log2_floor!(u16, u16_log2_floor, u16_generic_log2_floor_test);
+    test_generic_log2_floor!(u32, u32_log2_floor, u32_generic_log2_floor_test);
+    test_generic_log2_floor!(u64, u64_log2_floor, u64_generic_log2_floor_test);
+    test_generic_log2_floor!(u128, u128_log2_floor, u128_generic_log2_floor_test);
+    test_generic_log2_floor!(usize, usize_log2_floor, usize_generic_log2_floor_test);
+    test_generic_log2_floor!(
+        i8,
+        |x| i8_log2_floor(x).unwrap(),
+        i8_generic_log2_floor_test
+    );
+    test_generic_log2_floor!(
+        i16,
+        |x| i16_log2_floor(x).unwrap(),
+        i16_generic_log2_floor_test
+    );
+    test_generic_log2_floor!(
+        i32,
+        |x| i32_log2_floor(x).unwrap(),
+        i32_generic_log2_floor_test
+    );
+    test_generic_log2_floor!(
+        i64,
+        |x| i64_log2_floor(x).unwrap(),
+        i64_generic_log2_floor_test
+    );
+    test_generic_log2_floor!(
+        i128,
+        |x| i128_log2_floor(x).unwrap(),
+        i128_generic_log2_floor_test
+    );
+    test_generic_log2_floor!(
+        isize,
+        |x| isize_log2_floor(x).unwrap(),
+        isize_generic_log2_floor_test
+    );
+
+    /// Define a test function to test a log2_ceil function.
+    macro_rules! test_log2_ceil {
+        ($datatype:ty, $testfn:expr, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                // Powers of two and the adjacent numbers up to 2**(bits - 1)
+                for exponent in 2..<$datatype>::BITS {
+                    let base_value: $datatype = (1 << exponent);
+                    let prev_value: $datatype = base_value - 1;
+                    let next_value: $datatype = base_value + 1;
+
+                    // Test 2**exponent - 1
+                    assert_eq!(
+                        $testfn(prev_value),
+                        exponent,
+                        "Failed with x=2^{} - 1",
+                        exponent
+                    );
+
+                    // Test 2**exponent
+                    assert_eq!(
+                        $testfn(base_value),
+                        exponent,
+                        "Failed with x=2^{}",
+                        exponent
+                    );
+
+                    // Test 2**exponent + 1
+                    assert_eq!(
+                        $testfn(next_value),
+                        exponent + 1,
+                        "Failed with x=2^{} + 1",
+                        exponent
+                    );
+                }
+                // Special edge cases
+                assert_eq!($testfn(0), u32::MAX, "Failed with x=0");
+                assert_eq!($testfn(1), 0, "Failed with x=1");
+                assert_eq!($testfn(2), 1, "Failed with x=2");
+                assert_eq!($testfn(3), 2, "Failed with x=3");
+                assert_eq!(
+                    $testfn(<$datatype>::MAX),
+                    <$datatype>::BITS,
+                    "Failed with x=MAXINT"
+                );
+            }
+        };
+    }
+
+    test_log2_ceil!(u8, u8_log2_ceil, u8_log2_ceil_test);
+    test_log2_ceil!(u16, u16_log2_ceil, u16_log2_ceil_test);
+    test_log2_ceil!(u32, u32_log2_ceil, u32_log2_ceil_test);
+    test_log2_ceil!(u64, u64_log2_ceil, u64_log2_ceil_test);
+    test_log2_ceil!(u128, u128_log2_ceil, u128_log2_ceil_test);
+    test_log2_ceil!(usize, usize_log2_ceil, usize_log2_ceil_test);
+
+    /// Define a test function to test a bit_width function.
+    macro_rules! test_bit_width {
+        ($datatype:ty, $testfn:expr, $log2fn:expr, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                // Powers of two and the adjacent numbers up to 2**(bits - 1)
+                for exponent in 1..<$datatype>::BITS {
+                    let base_value: $datatype = (1 << exponent);
+                    assert_eq!(
+                        $testfn(base_value - 1),
+                        exponent,
+                        "Failed with x=2^{} - 1",
+                        exponent
+                    );
+                    assert_eq!(
+                        $testfn(base_value),
+                        exponent + 1,
+                        "Failed with x=2^{}",
+                        exponent
+                    );
+                    assert_eq!(
+                        $testfn(base_value),
+                        $log2fn(base_value) + 1,
+                        "Failed log2_floor consistency with x=2^{}",
+                        exponent
+                    );
+                }
+                // Special edge cases
+                assert_eq!($testfn(0), 0, "Failed with x=0");
+                assert_eq!($testfn(1), 1, "Failed with x=1");
+                assert_eq!(
+                    $testfn(<$datatype>::MAX),
+                    <$datatype>::BITS,
+                    "Failed with x=MAXINT"
+                );
+            }
+        };
+    }
+
+    test_bit_width!(u8, u8_bit_width, u8_log2_floor, u8_bit_width_test);
+    test_bit_width!(u16, u16_bit_width, u16_log2_floor, u16_bit_width_test);
+    test_bit_width!(u32, u32_bit_width, u32_log2_floor, u32_bit_width_test);
+    test_bit_width!(u64, u64_bit_width, u64_log2_floor, u64_bit_width_test);
+    test_bit_width!(u128, u128_bit_width, u128_log2_floor, u128_bit_width_test);
+    test_bit_width!(
+        usize,
+        usize_bit_width,
+        usize_log2_floor,
+        usize_bit_width_test
+    );
+
+    /// Define a test function to test a log10_floor function.
+    macro_rules! test_log10_floor {
+        ($datatype:ty, $testfn:expr, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                // Powers of ten and the adjacent numbers up to the largest representable power
+                let mut base_value: $datatype = 10;
+                for exponent in 1..=<$datatype>::MAX.ilog10() {
+                    let prev_value: $datatype = base_value - 1;
+                    let next_value: $datatype = base_value + 1;
+
+                    // Test 10**exponent - 1
+                    assert_eq!(
+                        $testfn(prev_value),
+                        exponent - 1,
+                        "Failed with x=10^{} - 1",
+                        exponent
+                    );
+
+                    // Test 10**exponent
+                    assert_eq!(
+                        $testfn(base_value),
+                        exponent,
+                        "Failed with x=10^{}",
+                        exponent
+                    );
+
+                    // Test 10**exponent + 1
+                    assert_eq!(
+                        $testfn(next_value),
+                        exponent,
+                        "Failed with x=10^{} + 1",
+                        exponent
+                    );
+                    base_value = base_value.wrapping_mul(10);
+                }
+                // Special edge cases
+                assert_eq!($testfn(0), u32::MAX, "Failed with x=0");
+                assert_eq!($testfn(1), 0, "Failed with x=1");
+                assert_eq!($testfn(9), 0, "Failed with x=9");
+                assert_eq!($testfn(10), 1, "Failed with x=10");
+                assert_eq!($testfn(99), 1, "Failed with x=99");
+                assert_eq!($testfn(100), 2, "Failed with x=100");
+                assert_eq!(
+                    $testfn(<$datatype>::MAX),
+                    <$datatype>::MAX.ilog10(),
+                    "Failed with x=MAXINT"
+                );
+            }
+        };
+    }
+
+    test_log10_floor!(u8, u8_log10_floor, u8_log10_floor_test);
+    test_log10_floor!(u16, u16_log10_floor, u16_log10_floor_test);
+    test_log10_floor!(u32, u32_log10_floor, u32_log10_floor_test);
+    test_log10_floor!(u64, u64_log10_floor, u64_log10_floor_test);
+    test_log10_floor!(u128, u128_log10_floor, u128_log10_floor_test);
+    test_log10_floor!(usize, usize_log10_floor, usize_log10_floor_test);
+
+    /// Compare log10_floor against the standard library for random values of all magnitudes.
+    #[test]
+    fn log10_floor_random_test() {
+        let mut rn = crate::rng::Lehmer64::new(0);
+        for _ in 0..10_000 {
+            // Shift by a random amount, so small values are as common as large ones.
+            let x = rn.generate_u128() >> rn.generate_range_u32(0, 128);
+            assert_eq!(
+                u128_log10_floor(x),
+                x.checked_ilog10().unwrap_or(u32::MAX),
+                "Failed with x={}",
+                x
+            );
+            assert_eq!(
+                u64_log10_floor(x as u64),
+                (x as u64).checked_ilog10().unwrap_or(u32::MAX),
+                "Failed with x={}",
+                x as u64
+            );
+        }
+    }
+
+    /// Define a test function to test an is_power_of_two function.
+    macro_rules! test_is_power_of_two {
+        ($datatype:ty, $testfn:expr, $log2fn:expr, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                // Powers of two and the adjacent numbers up to 2**(bits - 1)
+                for exponent in 2..<$datatype>::BITS {
+                    let base_value: $datatype = (1 << exponent);
+                    assert!(!$testfn(base_value - 1), "Failed with x=2^{} - 1", exponent);
+                    assert!($testfn(base_value), "Failed with x=2^{}", exponent);
+                    assert!(!$testfn(base_value + 1), "Failed with x=2^{} + 1", exponent);
+                    // Composes with log2_floor
+                    assert_eq!(
+                        1 << $log2fn(base_value),
+                        base_value,
+                        "Failed log2_floor composition with x=2^{}",
+                        exponent
+                    );
+                }
+                // Special edge cases
+                assert!(!$testfn(0), "Failed with x=0");
+                assert!($testfn(1), "Failed with x=1");
+                assert!($testfn(2), "Failed with x=2");
+                assert!(!$testfn(3), "Failed with x=3");
+                assert!(!$testfn(<$datatype>::MAX), "Failed with x=MAXINT");
+            }
+        };
+    }
+
+    test_is_power_of_two!(
+        u8,
+        is_power_of_two_u8,
+        u8_log2_floor,
+        is_power_of_two_u8_test
+    );
+    test_is_power_of_two!(
+        u16,
+        is_power_of_two_u16,
+        u16_log2_floor,
+        is_power_of_two_u16_test
+    );
+    test_is_power_of_two!(
+        u32,
+        is_power_of_two_u32,
+        u32_log2_floor,
+        is_power_of_two_u32_test
+    );
+    test_is_power_of_two!(
+        u64,
+        is_power_of_two_u64,
+        u64_log2_floor,
+        is_power_of_two_u64_test
+    );
+    test_is_power_of_two!(
+        u128,
+        is_power_of_two_u128,
+        u128_log2_floor,
+        is_power_of_two_u128_test
+    );
+    test_is_power_of_two!(
+        usize,
+        is_power_of_two_usize,
+        usize_log2_floor,
+        is_power_of_two_usize_test
+    );
+
+    /// Define a test function to test the generic is_power_of_two function.
+    macro_rules! test_generic_is_power_of_two {
+        ($datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                // Every power of two and the adjacent numbers
+                for exponent in 0..<$datatype>::BITS {
+                    let base_value = (1 as $datatype) << exponent;
+                    // For signed types the highest bit is the negative minimum.
+                    assert_eq!(
+                        is_power_of_two(base_value),
+                        base_value > 0,
+                        "Failed with x=2^{}",
+                        exponent
+                    );
+                    if exponent >= 2 {
+                        let prev_value = base_value.wrapping_sub(1);
+                        assert!(
+                            !is_power_of_two(prev_value),
+                            "Failed with x=2^{} - 1",
+                            exponent
+                        );
+                    }
+                    if exponent >= 1 {
+                        let next_value = base_value.wrapping_add(1);
+                        assert!(
+                            !is_power_of_two(next_value),
+                            "Failed with x=2^{} + 1",
+                            exponent
+                        );
+                    }
+                }
+                // Special edge cases
+                assert!(!is_power_of_two(0 as $datatype), "Failed with x=0");
+                assert!(is_power_of_two(1 as $datatype), "Failed with x=1");
+                assert!(!is_power_of_two(6 as $datatype), "Failed with x=6");
+                assert!(!is_power_of_two(100 as $datatype), "Failed with x=100");
+                assert!(!is_power_of_two(<$datatype>::MAX), "Failed with x=MAXINT");
+                assert!(
+                    !is_power_of_two(<$datatype>::MIN.wrapping_sub(1)),
+                    "Failed with x=MIN - 1"
+                );
+            }
+        };
+    }
+
+    test_generic_is_power_of_two!(u8, u8_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(u16, u16_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(u32, u32_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(u64, u64_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(u128, u128_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(usize, usize_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(i8, i8_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(i16, i16_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(i32, i32_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(i64, i64_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(i128, i128_generic_is_power_of_two_test);
+    test_generic_is_power_of_two!(isize, isize_generic_is_power_of_two_test);
+
+    /// Define a test function to test a next_power_of_two function and its checked variant.
+    macro_rules! test_next_power_of_two {
+        ($datatype:ty, $testfn:expr, $checkedfn:expr, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                // Powers of two and the adjacent numbers up to 2**(bits - 1)
+                for exponent in 1..<$datatype>::BITS {
+                    let base_value: $datatype = (1 << exponent);
+
+                    // Test 2**exponent
+                    assert_eq!(
+                        $testfn(base_value),
+                        base_value,
+                        "Failed with x=2^{}",
+                        exponent
+                    );
+                    assert_eq!(
+                        $checkedfn(base_value),
+                        Some(base_value),
+                        "Failed checked with x=2^{}",
+                        exponent
+                    );
+
+                    // Test 2**(exponent - 1) + 1
+                    let prev_next_value: $datatype = (base_value >> 1) + 1;
+                    assert_eq!(
+                        $testfn(prev_next_value),
+                        base_value,
+                        "Failed with x=2^{} + 1",
+                        exponent - 1
+                    );
+                    assert_eq!(
+                        $checkedfn(prev_next_value),
+                        Some(base_value),
+                        "Failed checked with x=2^{} + 1",
+                        exponent - 1
+                    );
+                }
+                // Special edge cases
+                assert_eq!($testfn(0), 1, "Failed with x=0");
+                assert_eq!($testfn(1), 1, "Failed with x=1");
+                assert_eq!($checkedfn(0), Some(1), "Failed checked with x=0");
+                assert_eq!($checkedfn(1), Some(1), "Failed checked with x=1");
+                // Overflow boundary
+                let top_power: $datatype = 1 << (<$datatype>::BITS - 1);
+                assert_eq!(
+                    $testfn(top_power - 1),
+                    top_power,
+                    "Failed with x=2^(BITS - 1) - 1"
+                );
+                assert_eq!($testfn(top_power), top_power, "Failed with x=2^(BITS - 1)");
+                let overflow_value: $datatype = top_power + 1;
+                assert_eq!($testfn(overflow_value), 0, "Failed with x=2^(BITS - 1) + 1");
+                assert_eq!($testfn(<$datatype>::MAX), 0, "Failed with x=MAXINT");
+                assert_eq!(
+                    $checkedfn(overflow_value),
+                    None,
+                    "Failed checked with x=2^(BITS - 1) + 1"
+                );
+                assert_eq!(
+                    $checkedfn(<$datatype>::MAX),
+                    None,
+                    "Failed checked with x=MAXINT"
+                );
+            }
+        };
+    }
+
+    test_next_power_of_two!(
+        u8,
+        u8_next_power_of_two,
+        u8_next_power_of_two_checked,
+        u8_next_power_of_two_test
+    );
+    test_next_power_of_two!(
+        u16,
+        u16_next_power_of_two,
+        u16_next_power_of_two_checked,
+        u16_next_power_of_two_test
+    );
+    test_next_power_of_two!(
+        u32,
+        u32_next_power_of_two,
+        u32_next_power_of_two_checked,
+        u32_next_power_of_two_test
+    );
+    test_next_power_of_two!(
+        u64,
+        u64_next_power_of_two,
+        u64_next_power_of_two_checked,
+        u64_next_power_of_two_test
+    );
+    test_next_power_of_two!(
+        u128,
+        u128_next_power_of_two,
+        u128_next_power_of_two_checked,
+        u128_next_power_of_two_test
+    );
+    test_next_power_of_two!(
+        usize,
+        usize_next_power_of_two,
+        usize_next_power_of_two_checked,
+        usize_next_power_of_two_test
+    );
+
+    /// Define a test function to test a prev_power_of_two function.
+    macro_rules! test_prev_power_of_two {
+        ($datatype:ty, $testfn:expr, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                // Powers of two and the adjacent numbers up to 2**(bits - 1)
+                for exponent in 2..<$datatype>::BITS {
+                    let base_value: $datatype = (1 << exponent);
+
+                    // Test 2**exponent - 1
+                    assert_eq!(
+                        $testfn(base_value - 1),
+                        base_value >> 1,
+                        "Failed with x=2^{} - 1",
+                        exponent
+                    );
+
+                    // Test 2**exponent
+                    assert_eq!(
+                        $testfn(base_value),
+                        base_value,
+                        "Failed with x=2^{}",
+                        exponent
+                    );
+
+                    // Test 2**exponent + 1
+                    assert_eq!(
+                        $testfn(base_value + 1),
+                        base_value,
+                        "Failed with x=2^{} + 1",
+                        exponent
+                    );
+                }
+                // Special edge cases
+                assert_eq!($testfn(0), 0, "Failed with x=0");
+                assert_eq!($testfn(1), 1, "Failed with x=1");
+                assert_eq!($testfn(2), 2, "Failed with x=2");
+                assert_eq!($testfn(3), 2, "Failed with x=3");
+                assert_eq!($testfn(4), 4, "Failed with x=4");
+                assert_eq!($testfn(5), 4, "Failed with x=5");
+                assert_eq!(
+                    $testfn(<$datatype>::MAX),
+                    1 << (<$datatype>::BITS - 1),
+                    "Failed with x=MAXINT"
+                );
+            }
+        };
+    }
+
+    test_prev_power_of_two!(u8, u8_prev_power_of_two, u8_prev_power_of_two_test);
+    test_prev_power_of_two!(u16, u16_prev_power_of_two, u16_prev_power_of_two_test);
+    test_prev_power_of_two!(u32, u32_prev_power_of_two, u32_prev_power_of_two_test);
+    test_prev_power_of_two!(u64, u64_prev_power_of_two, u64_prev_power_of_two_test);
+    test_prev_power_of_two!(u128, u128_prev_power_of_two, u128_prev_power_of_two_test);
+    test_prev_power_of_two!(usize, usize_prev_power_of_two, usize_prev_power_of_two_test);
+
+    /// Test the arbitrary base logarithm against known values and the specialized functions.
+    #[test]
+    fn u64_log_floor_test() {
+        // Consistency with the specialized functions
+        for exponent in 1..u64::BITS {
+            let base_value: u64 = 1 << exponent;
+            for x in [base_value - 1, base_value, base_value + 1] {
+                assert_eq!(
+                    u64_log_floor(x, 2),
+                    u64_log2_floor(x),
+                    "Failed with x={}",
+                    x
+                );
+                assert_eq!(
+                    u64_log_floor(x, 10),
+                    u64_log10_floor(x),
+                    "Failed with x={}",
+                    x
+                );
+            }
+        }
+        assert_eq!(u64_log_floor(u64::MAX, 2), u64_log2_floor(u64::MAX));
+        assert_eq!(u64_log_floor(u64::MAX, 10), u64_log10_floor(u64::MAX));
+
+        // Known values
+        assert_eq!(u64_log_floor(1, 3), 0);
+        assert_eq!(u64_log_floor(2, 3), 0);
+        assert_eq!(u64_log_floor(3, 3), 1);
+        assert_eq!(u64_log_floor(26, 3), 2);
+        assert_eq!(u64_log_floor(27, 3), 3);
+        assert_eq!(u64_log_floor(999, 10), 2);
+        assert_eq!(u64_log_floor(1000, 10), 3);
+        assert_eq!(u64_log_floor(0xff, 16), 1);
+        assert_eq!(u64_log_floor(0x100, 16), 2);
+        assert_eq!(u64_log_floor(u64::MAX, 16), 15);
+        assert_eq!(u64_log_floor(u64::MAX, u64::MAX), 1);
+        assert_eq!(u64_log_floor(u64::MAX - 1, u64::MAX), 0);
+
+        // Zero
+        assert_eq!(u64_log_floor(0, 3), u32::MAX);
+        assert_eq!(u64_log_floor_checked(0, 3), None);
+        assert_eq!(u64_log_floor_checked(81, 3), Some(4));
+    }
+
+    /// Compare u64_log_floor against the float logarithm where it is exact.
+    #[test]
+    fn u64_log_floor_float_test() {
+        for base in 2..=16u64 {
+            let mut next_power = base;
+            for x in 1..=100_000u64 {
+                // The float logarithm of exact powers is often rounded down, so they are skipped.
+                if x == next_power {
+                    next_power *= base;
+                    continue;
+                }
+                assert_eq!(
+                    u64_log_floor(x, base),
+                    (x as f64).log(base as f64).floor() as u32,
+                    "Failed with x={}, base={}",
+                    x,
+                    base
+                );
+            }
+        }
+
+        // Boundaries where the float logarithm is wrong.
+        // ln(x) / ln(base) is slightly below the exact result for these powers,
+        // or the input is rounded to a power when converting to f64.
+        assert_eq!(u64_log_floor(243, 3), 5);
+        assert_eq!(u64_log_floor(59049, 3), 10);
+        assert_eq!(u64_log_floor(1000, 10), 3);
+        assert_eq!(u64_log_floor(1_000_000, 10), 6);
+        assert_eq!(u64_log_floor(1_000_000_000_000_000, 10), 15);
+        assert_eq!(u64_log_floor(1_000_000_000_000_001, 10), 15);
+        assert_eq!(u64_log_floor(9_999_999_999_999_999, 10), 15);
+        assert_eq!(u64_log_floor(9_999_999_999_999_999_999, 10), 18);
+        assert_eq!(u64_log_floor(1_853_020_188_851_840, 3), 31);
+        assert_eq!(u64_log_floor(12_157_665_459_056_928_801, 3), 40);
+    }
+
+    /// Test that an invalid base panics.
+    #[test]
+    #[should_panic]
+    fn u64_log_floor_invalid_base_test() {
+        u64_log_floor(10, 1);
+    }
 }
@@ -0,0 +1,776 @@
+// fastmath - Various performance optimized math operations.
+// Copyright 2025 N. Dornseif
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! pow - Integer powers and roots.
+//!
+//! # Examples
+//! ```
+//! use fastmath::pow;
+//!
+//! assert_eq!(pow::isqrt_u64(99), 9);
+//! assert_eq!(pow::isqrt_ceil_u64(99), 10);
+//! assert!(pow::is_perfect_square_u64(100));
+//!
+//! assert_eq!(pow::icbrt_u64(1000), 10);
+//! assert_eq!(pow::icbrt_i64(-30), -3);
+//!
+//! assert_eq!(pow::pow_u64(10, 18), 1_000_000_000_000_000_000);
+//! assert_eq!(pow::checked_pow_u64(2, 64), None);
+//! assert_eq!(pow::saturating_pow_i32(-2, 33), i32::MIN);
+//!
+//! // Float imprecision causes the wrong value
+//! // to be returned for f64 (4294967296 instead of 4294967295).
+//! assert_eq!((u64::MAX as f64).sqrt() as u64, 4294967296);
+//! assert_eq!(pow::isqrt_u64(u64::MAX), 4294967295);
+//! ```
+
+use crate::log::{
+    u128_log2_floor, u16_log2_floor, u32_log2_floor, u64_log2_floor, u8_log2_floor,
+    usize_log2_floor,
+};
+
+/// Define functions for supplied datatype that compute integer square roots.
+macro_rules! generic_isqrt {
+    ($fnname:ident, $fnname_ceil:ident, $fnname_perfect:ident, $log2fn:ident, $datatype:ty) => {
+        /// Equivalent to floor(sqrt(x)).
+        #[inline]
+        pub const fn $fnname(x: $datatype) -> $datatype {
+            if x < 2 {
+                return x;
+            }
+            // Initial estimate is a power of two larger than sqrt(x),
+            // so Newton-Raphson iterations decrease monotonically to the result.
+            // The estimate is at most 2^(BITS / 2), so r + x / r can not overflow.
+            let mut r: $datatype = 1 << ($log2fn(x) / 2 + 1);
+            let mut next = (r + x / r) / 2;
+            while next < r {
+                r = next;
+                next = (r + x / r) / 2;
+            }
+            debug_assert!(r * r <= x, "Integer square root too large");
+            debug_assert!(
+                match (r + 1).checked_mul(r + 1) {
+                    Some(square) => x < square,
+                    None => true,
+                },
+                "Integer square root too small"
+            );
+            r
+        }
+
+        /// Equivalent to ceil(sqrt(x)).
+        #[inline]
+        pub const fn $fnname_ceil(x: $datatype) -> $datatype {
+            let r = $fnname(x);
+            // The floor root is at most 2^(BITS / 2) - 1, so r + 1 can not overflow.
+            if r * r == x {
+                r
+            } else {
+                r + 1
+            }
+        }
+
+        /// Returns true if x is the square of an integer.
+        #[inline]
+        pub const fn $fnname_perfect(x: $datatype) -> bool {
+            let r = $fnname(x);
+            r * r == x
+        }
+    };
+}
+
+generic_isqrt!(
+    isqrt_u8,
+    isqrt_ceil_u8,
+    is_perfect_square_u8,
+    u8_log2_floor,
+    u8
+);
+generic_isqrt!(
+    isqrt_u16,
+    isqrt_ceil_u16,
+    is_perfect_square_u16,
+    u16_log2_floor,
+    u16
+);
+generic_isqrt!(
+    isqrt_u32,
+    isqrt_ceil_u32,
+    is_perfect_square_u32,
+    u32_log2_floor,
+    u32
+);
+generic_isqrt!(
+    isqrt_u64,
+    isqrt_ceil_u64,
+    is_perfect_square_u64,
+    u64_log2_floor,
+    u64
+);
+generic_isqrt!(
+    isqrt_u128,
+    isqrt_ceil_u128,
+    is_perfect_square_u128,
+    u128_log2_floor,
+    u128
+);
+generic_isqrt!(
+    isqrt_usize,
+    isqrt_ceil_usize,
+    is_perfect_square_usize,
+    usize_log2_floor,
+    usize
+);
+
+/// Define functions for supplied datatype that compute integer cube roots.
+macro_rules! generic_icbrt {
+    ($fnname:ident, $fnname_perfect:ident, $datatype:ty) => {
+        /// Equivalent to floor(cbrt(x)).
+        #[inline]
+        pub const fn $fnname(x: $datatype) -> $datatype {
+            // Computes the root one bit at a time, starting at the highest bit position that is a multiple of 3.
+            // (y + 1)^3 = y^3 + 3y(y + 1) + 1, so the remainder is reduced by the difference of the cubes.
+            let mut remainder = x;
+            let mut y: $datatype = 0;
+            let mut shift = (<$datatype>::BITS - 1) / 3 * 3;
+            loop {
+                y <<= 1;
+                let difference = 3 * y * (y + 1) + 1;
+                // Comparing the shifted remainder avoids an overflow of difference << shift.
+                if remainder >> shift >= difference {
+                    remainder -= difference << shift;
+                    y += 1;
+                }
+                if shift == 0 {
+                    break;
+                }
+                shift -= 3;
+            }
+            debug_assert!(y * y * y <= x, "Integer cube root too large");
+            debug_assert!(
+                match (y + 1).checked_mul(y + 1) {
+                    Some(square) => match square.checked_mul(y + 1) {
+                        Some(cube) => x < cube,
+                        None => true,
+                    },
+                    None => true,
+                },
+                "Integer cube root too small"
+            );
+            y
+        }
+
+        /// Returns true if x is the cube of an integer.
+        #[inline]
+        pub const fn $fnname_perfect(x: $datatype) -> bool {
+            let r = $fnname(x);
+            r * r * r == x
+        }
+    };
+}
+
+generic_icbrt!(icbrt_u8, is_perfect_cube_u8, u8);
+generic_icbrt!(icbrt_u16, is_perfect_cube_u16, u16);
+generic_icbrt!(icbrt_u32, is_perfect_cube_u32, u32);
+generic_icbrt!(icbrt_u64, is_perfect_cube_u64, u64);
+generic_icbrt!(icbrt_u128, is_perfect_cube_u128, u128);
+generic_icbrt!(icbrt_usize, is_perfect_cube_usize, usize);
+
+/// Define a function for supplied signed datatype that computes integer cube roots.
+macro_rules! generic_signed_icbrt {
+    ($fnname:ident, $unsigned_fn:ident, $datatype:ty) => {
+        /// Equivalent to trunc(cbrt(x)).
+        /// The root of a negative value is the negated root of its absolute value.
+        #[inline]
+        pub const fn $fnname(x: $datatype) -> $datatype {
+            let root = $unsigned_fn(x.unsigned_abs()) as $datatype;
+            if x < 0 {
+                -root
+            } else {
+                root
+            }
+        }
+    };
+}
+
+generic_signed_icbrt!(icbrt_i8, icbrt_u8, i8);
+generic_signed_icbrt!(icbrt_i16, icbrt_u16, i16);
+generic_signed_icbrt!(icbrt_i32, icbrt_u32, i32);
+generic_signed_icbrt!(icbrt_i64, icbrt_u64, i64);
+generic_signed_icbrt!(icbrt_i128, icbrt_u128, i128);
+generic_signed_icbrt!(icbrt_isize, icbrt_usize, isize);
+
+/// Define functions for supplied datatype that raise an integer to an integer power.
+macro_rules! generic_pow {
+    ($fnname:ident, $fnname_checked:ident, $fnname_saturating:ident, $datatype:ty) => {
+        /// Equivalent to base^exp, computed by repeated squaring.
+        /// Panics on overflow in debug builds and wraps in release builds, like the * operator.
+        #[inline]
+        pub const fn $fnname(base: $datatype, exp: u32) -> $datatype {
+            let mut base = base;
+            let mut exp = exp;
+            let mut result: $datatype = 1;
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    result *= base;
+                }
+                exp >>= 1;
+                // Squaring after the last bit is skipped, it could overflow even if the result does not.
+                if exp > 0 {
+                    base *= base;
+                }
+            }
+            result
+        }
+
+        /// Equivalent to base^exp, computed by repeated squaring.
+        /// Returns None if the result overflows.
+        #[inline]
+        pub const fn $fnname_checked(base: $datatype, exp: u32) -> Option<$datatype> {
+            let mut base = base;
+            let mut exp = exp;
+            let mut result: $datatype = 1;
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    result = match result.checked_mul(base) {
+                        Some(product) => product,
+                        None => return None,
+                    };
+                }
+                exp >>= 1;
+                if exp > 0 {
+                    base = match base.checked_mul(base) {
+                        Some(square) => square,
+                        None => return None,
+                    };
+                }
+            }
+            Some(result)
+        }
+
+        /// Equivalent to base^exp, computed by repeated squaring.
+        /// Saturates at MIN or MAX, depending on the sign of the result, if it overflows.
+        #[inline]
+        pub const fn $fnname_saturating(base: $datatype, exp: u32) -> $datatype {
+            // If an intermediate value saturates, the final result overflows as well,
+            // saturating multiplication keeps the sign of the true product.
+            let mut base = base;
+            let mut exp = exp;
+            let mut result: $datatype = 1;
+            while exp > 0 {
+                if exp & 1 == 1 {
+                    result = result.saturating_mul(base);
+                }
+                exp >>= 1;
+                if exp > 0 {
+                    base = base.saturating_mul(base);
+                }
+            }
+            result
+        }
+    };
+}
+
+generic_pow!(pow_u8, checked_pow_u8, saturating_pow_u8, u8);
+generic_pow!(pow_u16, checked_pow_u16, saturating_pow_u16, u16);
+generic_pow!(pow_u32, checked_pow_u32, saturating_pow_u32, u32);
+generic_pow!(pow_u64, checked_pow_u64, saturating_pow_u64, u64);
+generic_pow!(pow_u128, checked_pow_u128, saturating_pow_u128, u128);
+generic_pow!(pow_usize, checked_pow_usize, saturating_pow_usize, usize);
+generic_pow!(pow_i8, checked_pow_i8, saturating_pow_i8, i8);
+generic_pow!(pow_i16, checked_pow_i16, saturating_pow_i16, i16);
+generic_pow!(pow_i32, checked_pow_i32, saturating_pow_i32, i32);
+generic_pow!(pow_i64, checked_pow_i64, saturating_pow_i64, i64);
+generic_pow!(pow_i128, checked_pow_i128, saturating_pow_i128, i128);
+generic_pow!(pow_isize, checked_pow_isize, saturating_pow_isize, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile time tests to ensure the integer square root functions remain const.
+    const _: () = assert!(isqrt_u32(1 << 20) == 1 << 10);
+    const _: () = assert!(isqrt_ceil_u64(17) == 5);
+    const _: () = assert!(is_perfect_square_u16(144));
+    const _: () = assert!(icbrt_u64(1 << 60) == 1 << 20);
+    const _: () = assert!(icbrt_i32(-27) == -3);
+    const _: () = assert!(is_perfect_cube_u32(125));
+    const _: () = assert!(pow_u64(3, 4) == 81);
+    const _: () = assert!(checked_pow_i32(2, 31).is_none());
+    const _: () = assert!(saturating_pow_u8(2, 8) == u8::MAX);
+
+    /// Defines a test function for integer square root functions.
+    macro_rules! test_isqrt {
+        ($testfn:expr, $testfn_ceil:expr, $testfn_perfect:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                for (x, expected) in [
+                    (0, 0),
+                    (1, 1),
+                    (2, 1),
+                    (3, 1),
+                    (4, 2),
+                    (5, 2),
+                    (8, 2),
+                    (9, 3),
+                ] {
+                    assert_eq!($testfn(x), expected, "Failed with x={}", x);
+                }
+                for (x, expected) in [(0, 0), (1, 1), (2, 2), (3, 2), (4, 2), (5, 3)] {
+                    assert_eq!($testfn_ceil(x), expected, "Failed ceil with x={}", x);
+                }
+                // Perfect squares up to 1000 and their neighbours, as far as the type allows.
+                let mut n: $datatype = 1;
+                while let Some(square) = n.checked_mul(n).filter(|&s| s as u128 <= 1000) {
+                    assert_eq!($testfn(square), n, "Failed with x={}^2", n);
+                    assert_eq!($testfn(square - 1), n - 1, "Failed with x={}^2 - 1", n);
+                    assert_eq!($testfn_ceil(square), n, "Failed ceil with x={}^2", n);
+                    assert!(
+                        $testfn_perfect(square),
+                        "Failed perfect square with x={}^2",
+                        n
+                    );
+                    if n > 1 {
+                        assert_eq!(
+                            $testfn_ceil(square - 1),
+                            n,
+                            "Failed ceil with x={}^2 - 1",
+                            n
+                        );
+                        assert!(
+                            !$testfn_perfect(square - 1),
+                            "Failed perfect square with x={}^2 - 1",
+                            n
+                        );
+                    }
+                    if let Some(next) = square.checked_add(1) {
+                        assert!(
+                            !$testfn_perfect(next),
+                            "Failed perfect square with x={}^2 + 1",
+                            n
+                        );
+                    }
+                    n += 1;
+                }
+                // The largest root that can be squared without overflow.
+                let max_root: $datatype = (1 << (<$datatype>::BITS / 2)) - 1;
+                assert_eq!($testfn(<$datatype>::MAX), max_root, "Failed with x=MAX");
+                assert_eq!(
+                    $testfn_ceil(<$datatype>::MAX),
+                    max_root + 1,
+                    "Failed ceil with x=MAX"
+                );
+                assert_eq!(
+                    $testfn(max_root * max_root),
+                    max_root,
+                    "Failed with x=max_root^2"
+                );
+                assert!(
+                    !$testfn_perfect(<$datatype>::MAX),
+                    "Failed perfect square with x=MAX"
+                );
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..1000 {
+                    // Shift by a random amount, so small values are as common as large ones.
+                    let x: $datatype =
+                        rn.generate::<$datatype>() >> rn.generate_range_u32(0, <$datatype>::BITS);
+                    let r = $testfn(x);
+                    assert!(r * r <= x, "Failed with x={}", x);
+                    assert!(
+                        (r + 1).checked_mul(r + 1).map_or(true, |s| x < s),
+                        "Failed with x={}",
+                        x
+                    );
+                }
+            }
+        };
+    }
+
+    test_isqrt!(
+        isqrt_u8,
+        isqrt_ceil_u8,
+        is_perfect_square_u8,
+        u8,
+        test_u8_isqrt
+    );
+    test_isqrt!(
+        isqrt_u16,
+        isqrt_ceil_u16,
+        is_perfect_square_u16,
+        u16,
+        test_u16_isqrt
+    );
+    test_isqrt!(
+        isqrt_u32,
+        isqrt_ceil_u32,
+        is_perfect_square_u32,
+        u32,
+        test_u32_isqrt
+    );
+    test_isqrt!(
+        isqrt_u64,
+        isqrt_ceil_u64,
+        is_perfect_square_u64,
+        u64,
+        test_u64_isqrt
+    );
+    test_isqrt!(
+        isqrt_u128,
+        isqrt_ceil_u128,
+        is_perfect_square_u128,
+        u128,
+        test_u128_isqrt
+    );
+    test_isqrt!(
+        isqrt_usize,
+        isqrt_ceil_usize,
+        is_perfect_square_usize,
+        usize,
+        test_usize_isqrt
+    );
+
+    /// Compare the integer square root against the standard library for all u16 values.
+    #[test]
+    fn isqrt_u16_exhaustive_test() {
+        for x in 0..=u16::MAX {
+            assert_eq!(isqrt_u16(x), x.isqrt(), "Failed with x={}", x);
+            assert_eq!(
+                isqrt_u32(x as u32),
+                (x as u32).isqrt(),
+                "Failed with x={}",
+                x
+            );
+        }
+    }
+
+    /// Compare the integer square root against the standard library near perfect squares.
+    #[test]
+    fn isqrt_u64_near_squares_test() {
+        let mut rn = crate::rng::Lehmer64::new(0);
+        for _ in 0..10_000 {
+            let root = rn.generate_u32() as u64;
+            let square = root * root;
+            for x in [square.saturating_sub(1), square, square.saturating_add(1)] {
+                assert_eq!(isqrt_u64(x), x.isqrt(), "Failed with x={}", x);
+                assert_eq!(
+                    isqrt_u128(x as u128 * x as u128),
+                    x as u128,
+                    "Failed with x={}^2",
+                    x
+                );
+            }
+        }
+    }
+
+    /// Defines a test function for integer cube root functions.
+    macro_rules! test_icbrt {
+        ($testfn:expr, $testfn_perfect:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                for (x, expected) in [
+                    (0, 0),
+                    (1, 1),
+                    (7, 1),
+                    (8, 2),
+                    (26, 2),
+                    (27, 3),
+                    (63, 3),
+                    (64, 4),
+                    (125, 5),
+                ] {
+                    assert_eq!($testfn(x), expected, "Failed with x={}", x);
+                }
+                let mut n: $datatype = 1;
+                while let Some(cube) = n.checked_mul(n).and_then(|s| s.checked_mul(n)) {
+                    assert_eq!($testfn(cube), n, "Failed with x={}^3", n);
+                    assert_eq!($testfn(cube - 1), n - 1, "Failed with x={}^3 - 1", n);
+                    assert!($testfn_perfect(cube), "Failed perfect cube with x={}^3", n);
+                    if n > 1 {
+                        assert!(
+                            !$testfn_perfect(cube - 1),
+                            "Failed perfect cube with x={}^3 - 1",
+                            n
+                        );
+                    }
+                    // Test all cubes for small types, powers of two for large ones.
+                    n = if <$datatype>::BITS <= 16 {
+                        n + 1
+                    } else {
+                        n * 2
+                    };
+                }
+                assert!(
+                    !$testfn_perfect(<$datatype>::MAX),
+                    "Failed perfect cube with x=MAX"
+                );
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..1000 {
+                    // Shift by a random amount, so small values are as common as large ones.
+                    let x: $datatype =
+                        rn.generate::<$datatype>() >> rn.generate_range_u32(0, <$datatype>::BITS);
+                    let r = $testfn(x);
+                    assert!(r * r * r <= x, "Failed with x={}", x);
+                    assert!(
+                        (r + 1)
+                            .checked_mul(r + 1)
+                            .and_then(|s| s.checked_mul(r + 1))
+                            .map_or(true, |c| x < c),
+                        "Failed with x={}",
+                        x
+                    );
+                }
+            }
+        };
+    }
+
+    test_icbrt!(icbrt_u8, is_perfect_cube_u8, u8, test_u8_icbrt);
+    test_icbrt!(icbrt_u16, is_perfect_cube_u16, u16, test_u16_icbrt);
+    test_icbrt!(icbrt_u32, is_perfect_cube_u32, u32, test_u32_icbrt);
+    test_icbrt!(icbrt_u64, is_perfect_cube_u64, u64, test_u64_icbrt);
+    test_icbrt!(icbrt_u128, is_perfect_cube_u128, u128, test_u128_icbrt);
+    test_icbrt!(icbrt_usize, is_perfect_cube_usize, usize, test_usize_icbrt);
+
+    /// Test the integer cube root of the largest values.
+    #[test]
+    fn icbrt_max_test() {
+        assert_eq!(icbrt_u8(u8::MAX), 6);
+        assert_eq!(icbrt_u16(u16::MAX), 40);
+        assert_eq!(icbrt_u32(u32::MAX), 1625);
+        assert_eq!(icbrt_u64(u64::MAX), 2642245);
+        assert_eq!(icbrt_u128(u128::MAX), 6981463658331);
+        assert_eq!(icbrt_u64(1000), 10);
+        assert_eq!(icbrt_u64(999), 9);
+        assert_eq!(icbrt_u64(1_000_000_000_000), 10_000);
+        assert_eq!(icbrt_u64(123_456_789), 497);
+    }
+
+    /// Defines a test function for signed integer cube root functions.
+    macro_rules! test_signed_icbrt {
+        ($testfn:expr, $unsigned_fn:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                for (x, expected) in [
+                    (0, 0),
+                    (1, 1),
+                    (-1, -1),
+                    (7, 1),
+                    (-7, -1),
+                    (8, 2),
+                    (-8, -2),
+                    (-9, -2),
+                    (-27, -3),
+                ] {
+                    assert_eq!($testfn(x), expected, "Failed with x={}", x);
+                }
+                assert_eq!(
+                    $testfn(<$datatype>::MAX),
+                    $unsigned_fn(<$datatype>::MAX.unsigned_abs()) as $datatype,
+                    "Failed with x=MAX"
+                );
+                // 2^(BITS - 1) is a perfect cube if BITS - 1 is a multiple of 3.
+                assert_eq!(
+                    $testfn(<$datatype>::MIN),
+                    -($unsigned_fn(<$datatype>::MIN.unsigned_abs()) as $datatype),
+                    "Failed with x=MIN"
+                );
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..1000 {
+                    // MIN is excluded, its negation overflows.
+                    let x: $datatype = (rn.generate::<$datatype>()
+                        >> rn.generate_range_u32(0, <$datatype>::BITS))
+                    .max(<$datatype>::MIN + 1);
+                    assert_eq!($testfn(x), -$testfn(-x), "Failed symmetry with x={}", x);
+                }
+            }
+        };
+    }
+
+    test_signed_icbrt!(icbrt_i8, icbrt_u8, i8, test_i8_icbrt);
+    test_signed_icbrt!(icbrt_i16, icbrt_u16, i16, test_i16_icbrt);
+    test_signed_icbrt!(icbrt_i32, icbrt_u32, i32, test_i32_icbrt);
+    test_signed_icbrt!(icbrt_i64, icbrt_u64, i64, test_i64_icbrt);
+    test_signed_icbrt!(icbrt_i128, icbrt_u128, i128, test_i128_icbrt);
+    test_signed_icbrt!(icbrt_isize, icbrt_usize, isize, test_isize_icbrt);
+
+    /// Defines a test function for integer power functions.
+    macro_rules! test_pow {
+        ($testfn:expr, $testfn_checked:expr, $testfn_saturating:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                const MAX: $datatype = <$datatype>::MAX;
+                const MIN: $datatype = <$datatype>::MIN;
+                for base in [0, 1, 2, 3, MAX - 1, MAX, MIN] {
+                    assert_eq!($testfn(base, 0), 1, "Failed with base={}, exp=0", base);
+                    assert_eq!($testfn(base, 1), base, "Failed with base={}, exp=1", base);
+                }
+                assert_eq!($testfn(0, 5), 0, "Failed with base=0, exp=5");
+                assert_eq!($testfn(1, u32::MAX), 1, "Failed with base=1, exp=MAX");
+                assert_eq!(
+                    $testfn(2, <$datatype>::BITS - 2),
+                    1 << (<$datatype>::BITS - 2),
+                    "Failed with base=2, exp=BITS - 2"
+                );
+                assert_eq!(
+                    $testfn_checked(0, u32::MAX),
+                    Some(0),
+                    "Failed checked with base=0, exp=MAX"
+                );
+                assert_eq!(
+                    $testfn_checked(2, <$datatype>::BITS),
+                    None,
+                    "Failed checked with base=2, exp=BITS"
+                );
+                assert_eq!(
+                    $testfn_checked(MAX, 2),
+                    None,
+                    "Failed checked with base=MAX, exp=2"
+                );
+                assert_eq!(
+                    $testfn_saturating(MAX, 2),
+                    MAX,
+                    "Failed saturating with base=MAX, exp=2"
+                );
+                assert_eq!(
+                    $testfn_saturating(2, u32::MAX),
+                    MAX,
+                    "Failed saturating with base=2, exp=MAX"
+                );
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..10_000 {
+                    // Small bases and exponents, so not every result overflows.
+                    let base: $datatype =
+                        rn.generate::<$datatype>() >> rn.generate_range_u32(0, <$datatype>::BITS);
+                    let exp = rn.generate_range_u32(0, <$datatype>::BITS + 1);
+                    assert_eq!(
+                        $testfn_checked(base, exp),
+                        base.checked_pow(exp),
+                        "Failed checked with base={}, exp={}",
+                        base,
+                        exp
+                    );
+                    assert_eq!(
+                        $testfn_saturating(base, exp),
+                        base.saturating_pow(exp),
+                        "Failed saturating with base={}, exp={}",
+                        base,
+                        exp
+                    );
+                    if let Some(expected) = base.checked_pow(exp) {
+                        assert_eq!(
+                            $testfn(base, exp),
+                            expected,
+                            "Failed with base={}, exp={}",
+                            base,
+                            exp
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    test_pow!(pow_u8, checked_pow_u8, saturating_pow_u8, u8, test_u8_pow);
+    test_pow!(
+        pow_u16,
+        checked_pow_u16,
+        saturating_pow_u16,
+        u16,
+        test_u16_pow
+    );
+    test_pow!(
+        pow_u32,
+        checked_pow_u32,
+        saturating_pow_u32,
+        u32,
+        test_u32_pow
+    );
+    test_pow!(
+        pow_u64,
+        checked_pow_u64,
+        saturating_pow_u64,
+        u64,
+        test_u64_pow
+    );
+    test_pow!(
+        pow_u128,
+        checked_pow_u128,
+        saturating_pow_u128,
+        u128,
+        test_u128_pow
+    );
+    test_pow!(
+        pow_usize,
+        checked_pow_usize,
+        saturating_pow_usize,
+        usize,
+        test_usize_pow
+    );
+    test_pow!(pow_i8, checked_pow_i8, saturating_pow_i8, i8, test_i8_pow);
+    test_pow!(
+        pow_i16,
+        checked_pow_i16,
+        saturating_pow_i16,
+        i16,
+        test_i16_pow
+    );
+    test_pow!(
+        pow_i32,
+        checked_pow_i32,
+        saturating_pow_i32,
+        i32,
+        test_i32_pow
+    );
+    test_pow!(
+        pow_i64,
+        checked_pow_i64,
+        saturating_pow_i64,
+        i64,
+        test_i64_pow
+    );
+    test_pow!(
+        pow_i128,
+        checked_pow_i128,
+        saturating_pow_i128,
+        i128,
+        test_i128_pow
+    );
+    test_pow!(
+        pow_isize,
+        checked_pow_isize,
+        saturating_pow_isize,
+        isize,
+        test_isize_pow
+    );
+
+    /// Test integer powers with known values near the limits of the types.
+    #[test]
+    fn pow_values_test() {
+        assert_eq!(pow_u64(2, 63), 1 << 63);
+        assert_eq!(pow_u64(10, 18), 1_000_000_000_000_000_000);
+        assert_eq!(pow_u64(10, 19), 10_000_000_000_000_000_000);
+        assert_eq!(checked_pow_u64(10, 20), None);
+        assert_eq!(checked_pow_u64(2, 64), None);
+        assert_eq!(saturating_pow_u64(u64::MAX, 2), u64::MAX);
+        assert_eq!(pow_u128(3, 80), 3u128.pow(80));
+        assert_eq!(pow_i64(-2, 63), i64::MIN);
+        assert_eq!(checked_pow_i64(-2, 64), None);
+        assert_eq!(checked_pow_i64(2, 63), None);
+        assert_eq!(saturating_pow_i64(-2, 65), i64::MIN);
+        assert_eq!(saturating_pow_i64(-2, 64), i64::MAX);
+        assert_eq!(pow_i8(-3, 3), -27);
+    }
+
+    /// Test that overflowing powers panic in debug builds.
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn pow_overflow_panic_test() {
+        pow_u64(2, 64);
+    }
+}
@@ -24,8 +24,12 @@
 //! assert_eq!(rn.generate_weighted_bool(0.95), true);
 //! ```
 
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
 use crate::consts::double::INV_2POW53;
 use crate::consts::float::INV_2POW24;
+use crate::consts::integer::GOLDEN_RATIO_64;
+use crate::traits::BaseInt;
 
 /// Define a function that generates a random result of the specified datatype.
 macro_rules! generic_generation_function {
@@ -39,21 +43,305 @@ macro_rules! generic_generation_function {
     };
 }
 
+/// Define the generation functions that are derived from `generate_u32` and `generate_u64`.
+/// Used by generators that do not produce 64 high bits of a u128 state like `Lehmer64`.
+macro_rules! derived_generation_functions {
+    () => {
+        /// Generates a 'random' u8 and advances the generator state like `generate_u32`.
+        #[inline]
+        pub fn generate_u8(&mut self) -> u8 {
+            self.generate_u32() as u8
+        }
+
+        /// Generates a 'random' u16 and advances the generator state like `generate_u32`.
+        #[inline]
+        pub fn generate_u16(&mut self) -> u16 {
+            self.generate_u32() as u16
+        }
+
+        /// Generates a 'random' usize and advances the generator state like `generate_u64`.
+        #[inline]
+        pub fn generate_usize(&mut self) -> usize {
+            self.generate_u64() as usize
+        }
+
+        /// Generates a 'random' u128 and advances the generator state like two `generate_u64` calls.
+        #[inline]
+        pub fn generate_u128(&mut self) -> u128 {
+            let high_bits = self.generate_u64() as u128;
+            (high_bits << 64) | self.generate_u64() as u128
+        }
+
+        /// Generates a 'random' i8 and advances the generator state like `generate_u32`.
+        #[inline]
+        pub fn generate_i8(&mut self) -> i8 {
+            self.generate_u32() as i8
+        }
+
+        /// Generates a 'random' i16 and advances the generator state like `generate_u32`.
+        #[inline]
+        pub fn generate_i16(&mut self) -> i16 {
+            self.generate_u32() as i16
+        }
+
+        /// Generates a 'random' i32 and advances the generator state like `generate_u32`.
+        #[inline]
+        pub fn generate_i32(&mut self) -> i32 {
+            self.generate_u32() as i32
+        }
+
+        /// Generates a 'random' i64 and advances the generator state like `generate_u64`.
+        #[inline]
+        pub fn generate_i64(&mut self) -> i64 {
+            self.generate_u64() as i64
+        }
+
+        /// Generates a 'random' isize and advances the generator state like `generate_u64`.
+        #[inline]
+        pub fn generate_isize(&mut self) -> isize {
+            self.generate_u64() as isize
+        }
+
+        /// Generates a 'random' i128 and advances the generator state like two `generate_u64` calls.
+        #[inline]
+        pub fn generate_i128(&mut self) -> i128 {
+            self.generate_u128() as i128
+        }
+
+        /// Generates a 'random' f64 in the range [0; 1)
+        /// and advances the generator state like `generate_u64`.
+        /// Has 53 bits of effective entropy
+        /// and does not produce all possible values in the range.
+        #[inline]
+        pub fn generate_f64(&mut self) -> f64 {
+            (self.generate_u64() >> 11) as f64 * INV_2POW53
+        }
+
+        /// Generates a 'random' f32 in the range [0; 1)
+        /// and advances the generator state like `generate_u32`.
+        /// Has 24 bits of effective entropy
+        /// and does not produce all possible values in the range.
+        #[inline]
+        pub fn generate_f32(&mut self) -> f32 {
+            (self.generate_u32() >> 8) as f32 * INV_2POW24
+        }
+
+        /// Generates a 'random' boolean and advances the generator state like `generate_u32`.
+        /// Where the distribution of true and false is 50/50.
+        #[inline]
+        pub fn generate_bool(&mut self) -> bool {
+            self.generate_u8() & 1 != 0
+        }
+
+        /// Generate a 'random' f64 that can take any possible value, including NaN, inf, ect.
+        /// Advances the generator state like `generate_u64`.
+        #[inline]
+        pub fn generate_any_f64(&mut self) -> f64 {
+            f64::from_bits(self.generate_u64())
+        }
+
+        /// Generate a 'random' f32 that can take any possible value, including NaN, inf, ect.
+        /// Advances the generator state like `generate_u32`.
+        #[inline]
+        pub fn generate_any_f32(&mut self) -> f32 {
+            f32::from_bits(self.generate_u32())
+        }
+
+        /// Generate a 'random' bool with a specified chance of being true.
+        /// Where chances are expressed as fractions of one. E.g 0.75 is 75 %
+        /// Advances the generator state like `generate_u32`.
+        #[inline]
+        pub fn generate_weighted_bool(&mut self, chance: f32) -> bool {
+            self.generate_f32() < chance
+        }
+    };
+}
+
+/// Define a function that generates a random nonzero integer of the specified datatype.
+macro_rules! generic_nonzero_generation_function {
+    ($fnname:ident, $genfn:ident, $nonzero:ty) => {
+        /// Generates a 'random' nonzero integer and advances the generator state at least one step.
+        /// A generated zero is discarded and replaced by the next value.
+        #[inline]
+        pub fn $fnname(&mut self) -> $nonzero {
+            loop {
+                if let Some(val) = <$nonzero>::new(self.$genfn()) {
+                    return val;
+                }
+            }
+        }
+    };
+}
+
+/// Define functions that generate a random integer of the specified datatype in a range.
+/// The range width is calculated in the unsigned counterpart, so it can not overflow.
+macro_rules! generic_range_function {
+    ($fnname:ident, $try_fnname:ident, $datatype:ty, $unsigned:ty) => {
+        /// Generates a 'random' integer in the range [lo; hi)
+        /// and advances the generator state at least one step.
+        /// The distribution is exactly uniform.
+        /// Returns `None` if lo >= hi.
+        #[inline]
+        pub fn $try_fnname(&mut self, lo: $datatype, hi: $datatype) -> Option<$datatype> {
+            if lo >= hi {
+                return None;
+            }
+            let range = hi.wrapping_sub(lo) as $unsigned;
+            let offset = self.generate_bounded_u64(range as u64) as $unsigned;
+            Some(lo.wrapping_add(offset as $datatype))
+        }
+
+        /// Generates a 'random' integer in the range [lo; hi)
+        /// and advances the generator state at least one step.
+        /// The distribution is exactly uniform.
+        ///
+        /// # Panics
+        /// Panics if lo >= hi.
+        #[inline]
+        pub fn $fnname(&mut self, lo: $datatype, hi: $datatype) -> $datatype {
+            self.$try_fnname(lo, hi)
+                .expect("Empty range: lo must be smaller than hi")
+        }
+    };
+}
+
+/// Common interface of the generators, for code that is generic over the generator type.  
+/// Only `generate_u64` and `generate_u32` are required,
+/// the other functions are derived from them by default.
+/// The generators in this module implement every function
+/// identically to their inherent functions of the same name.
+pub trait RandomGenerator {
+    /// Generates a 'random' u64 and advances the generator state.
+    fn generate_u64(&mut self) -> u64;
+
+    /// Generates a 'random' u32 and advances the generator state.
+    fn generate_u32(&mut self) -> u32;
+
+    /// Generates a 'random' u16 and advances the generator state like `generate_u64`.
+    #[inline]
+    fn generate_u16(&mut self) -> u16 {
+        self.generate_u64() as u16
+    }
+
+    /// Generates a 'random' u8 and advances the generator state like `generate_u64`.
+    #[inline]
+    fn generate_u8(&mut self) -> u8 {
+        self.generate_u64() as u8
+    }
+
+    /// Generates a 'random' usize and advances the generator state like `generate_u64`.
+    #[inline]
+    fn generate_usize(&mut self) -> usize {
+        self.generate_u64() as usize
+    }
+
+    /// Generates a 'random' i64 and advances the generator state like `generate_u64`.
+    #[inline]
+    fn generate_i64(&mut self) -> i64 {
+        self.generate_u64() as i64
+    }
+
+    /// Generates a 'random' f64 in the range [0; 1)
+    /// and advances the generator state like `generate_u64`.
+    /// Has 53 bits of effective entropy
+    /// and does not produce all possible values in the range.
+    #[inline]
+    fn generate_f64(&mut self) -> f64 {
+        (self.generate_u64() >> 11) as f64 * INV_2POW53
+    }
+
+    /// Generates a 'random' f32 in the range [0; 1)
+    /// and advances the generator state like `generate_u64`.
+    /// Has 24 bits of effective entropy
+    /// and does not produce all possible values in the range.
+    #[inline]
+    fn generate_f32(&mut self) -> f32 {
+        (self.generate_u64() as u32 >> 8) as f32 * INV_2POW24
+    }
+
+    /// Generates a 'random' boolean and advances the generator state like `generate_u64`.
+    /// Where the distribution of true and false is 50/50.
+    #[inline]
+    fn generate_bool(&mut self) -> bool {
+        self.generate_u64() & 1 != 0
+    }
+
+    /// Generate a 'random' bool with a specified chance of being true.
+    /// Where chances are expressed as fractions of one. E.g 0.75 is 75 %
+    /// Advances the generator state like `generate_f32`.
+    #[inline]
+    fn generate_weighted_bool(&mut self, chance: f32) -> bool {
+        self.generate_f32() < chance
+    }
+}
+
+/// Implement `RandomGenerator` by forwarding to the inherent functions of the generator.
+macro_rules! impl_random_generator {
+    ($($generator:ty),*) => {
+        $(impl RandomGenerator for $generator {
+            #[inline]
+            fn generate_u64(&mut self) -> u64 {
+                <$generator>::generate_u64(self)
+            }
+            #[inline]
+            fn generate_u32(&mut self) -> u32 {
+                <$generator>::generate_u32(self)
+            }
+            #[inline]
+            fn generate_u16(&mut self) -> u16 {
+                <$generator>::generate_u16(self)
+            }
+            #[inline]
+            fn generate_u8(&mut self) -> u8 {
+                <$generator>::generate_u8(self)
+            }
+            #[inline]
+            fn generate_usize(&mut self) -> usize {
+                <$generator>::generate_usize(self)
+            }
+            #[inline]
+            fn generate_i64(&mut self) -> i64 {
+                <$generator>::generate_i64(self)
+            }
+            #[inline]
+            fn generate_f64(&mut self) -> f64 {
+                <$generator>::generate_f64(self)
+            }
+            #[inline]
+            fn generate_f32(&mut self) -> f32 {
+                <$generator>::generate_f32(self)
+            }
+            #[inline]
+            fn generate_bool(&mut self) -> bool {
+                <$generator>::generate_bool(self)
+            }
+            #[inline]
+            fn generate_weighted_bool(&mut self, chance: f32) -> bool {
+                <$generator>::generate_weighted_bool(self, chance)
+            }
+        }
+    )*};
+}
+
 #[derive(Debug, Copy, Clone)]
 /// Fast high quality LCG PRNG
 /// but NOT cryptographically secure.
 pub struct Lehmer64 {
     state: u128,
+    // Second standard normal value produced by the last Box-Muller transform.
+    #[cfg(feature = "libm")]
+    gaussian_spare: Option<f64>,
 }
 impl Lehmer64 {
     const DEFAULT_SEED: u128 = 0xfe1f873c7fc74fa65743b339f566f7bb;
     const MUL_CONSTANT: u128 = 0xda942042e4dd58b5;
     /// Initalize a new RNG with the specified seed.  
     /// Where the seed is the intial internal state.  
-    /// If the seed is zero, it is replaced with a predefined strong default.
-    pub fn new(seed: u128) -> Self {
+    /// If the seed is zero, it is replaced with a predefined strong default.  
+    /// Can be evaluated at compile time, e.g. `const INITIAL: Lehmer64 = Lehmer64::new(0);`
+    pub const fn new(seed: u128) -> Self {
         let state = if seed == 0 { Self::DEFAULT_SEED } else { seed };
-        let mut new_rng = Lehmer64 { state };
+        let mut new_rng = Self::from_state(state);
         // Shuffle the internal state twice.
         // This prevents the first value from being low if the seed was a small number.
         new_rng.advance();
@@ -61,18 +349,113 @@ impl Lehmer64 {
         new_rng
     }
 
+    /// Initalize a new RNG from a 16 byte seed.  
+    /// The bytes are interpreted as a little-endian u128 seed,
+    /// which is then handled identically to `new`.
+    pub const fn from_bytes(seed: [u8; 16]) -> Self {
+        Self::new(u128::from_le_bytes(seed))
+    }
+
+    /// Initalize a new RNG from a 64 bit seed.  
+    /// The seed is expanded to 128 bits with two steps of `SplitMix64`,
+    /// which is then handled identically to `new`.
+    /// Similar seeds like counters produce unrelated sequences.
+    pub fn from_seed_u64(seed: u64) -> Self {
+        Self::new(SplitMix64::new(seed).generate_u128())
+    }
+
+    /// Initialize a RNG with exactly the specified internal state,
+    /// as previously returned by `state`.  
+    /// Unlike `new`, this does NOT replace a zero state with the strong default
+    /// and does NOT shuffle the state before the first value is generated.
+    ///
+    /// # Panics
+    /// Panics if the state is zero, which is never returned by `state`.
+    /// A zero state would only ever produce zeros.
+    pub const fn from_state(state: u128) -> Self {
+        assert!(state != 0, "State must not be zero");
+        Lehmer64 {
+            state,
+            #[cfg(feature = "libm")]
+            gaussian_spare: None,
+        }
+    }
+
+    /// Returns the internal state.  
+    /// Can be passed to `from_state` to resume generation at exactly this point.  
+    /// The cached second value of `generate_gaussian_f64` is not part of the state.
+    pub const fn state(&self) -> u128 {
+        self.state
+    }
+
     /// Advances the generator state one step.
     #[inline(always)]
-    fn advance(&mut self) {
+    const fn advance(&mut self) {
         self.state = self.state.wrapping_mul(Self::MUL_CONSTANT);
     }
 
+    /// Advances the generator state n steps in O(log n) time.  
+    /// Equivalent to calling a single step generation function n times.  
+    /// To give N threads disjoint subsequences of length L,
+    /// thread k can start from a copy of the same generator advanced by k * L.
+    pub const fn advance_by(&mut self, n: u64) {
+        // Multiplying n times by MUL_CONSTANT is equivalent to
+        // multiplying once by MUL_CONSTANT^n mod 2^128.
+        let mut multiplier: u128 = 1;
+        let mut square = Self::MUL_CONSTANT;
+        let mut remaining = n;
+        while remaining != 0 {
+            if remaining & 1 != 0 {
+                multiplier = multiplier.wrapping_mul(square);
+            }
+            square = square.wrapping_mul(square);
+            remaining >>= 1;
+        }
+        self.state = self.state.wrapping_mul(multiplier);
+    }
+
+    /// Advances the generator state by exactly the given number of steps in O(log steps) time.  
+    /// Identical to `advance_by`, named after the jump functions of other generators.  
+    /// Unlike `Xoshiro256StarStar::jump`, the distance is chosen by the caller.
+    pub const fn jump(&mut self, steps: u64) {
+        self.advance_by(steps);
+    }
+
+    /// Splits off a new generator for use as an independent stream.  
+    /// The child is seeded through `from_seed_u64` with the next `generate_u64` output,
+    /// which advances this generator one step.  
+    /// The SplitMix64 seed expansion places the child at an unrelated position in the period,
+    /// so a child never continues the stream of its parent, even when children are split again.  
+    /// Two streams of length L overlap with a probability of roughly L / 2^125.
+    /// This is a practical guarantee, not a strict partition of the period.
+    pub fn split(&mut self) -> Lehmer64 {
+        Self::from_seed_u64(self.generate_u64())
+    }
+
     generic_generation_function!(generate_u8, u8);
     generic_generation_function!(generate_u16, u16);
     generic_generation_function!(generate_u32, u32);
     generic_generation_function!(generate_u64, u64);
     generic_generation_function!(generate_usize, usize);
 
+    /// Generates two 'random' u64 and advances the generator state one step.  
+    /// The first value is identical to `generate_u64`,
+    /// the second is the low half of the state run through the SplitMix64 finalizer.  
+    /// This halves the number of generator steps for bulk generation,
+    /// but the finalizer adds two multiplications, so the speedup is well below two.
+    /// It comes at the cost of quality:
+    /// The low half of the state only depends on the previous low half,
+    /// so the second values repeat after at most 2^62 steps.
+    /// The finalizer hides the weak low bits, but the second values are not as good as the first.
+    #[inline]
+    pub fn generate_u64_pair(&mut self) -> (u64, u64) {
+        self.advance();
+        (
+            (self.state >> 64) as u64,
+            SplitMix64::mix(self.state as u64),
+        )
+    }
+
     // We define a seperate function for 128bit datatypes since they need two generator steps.
     /// Generates a 'random' u128 and advances the generator state two steps.
     #[inline]
@@ -99,6 +482,13 @@ impl Lehmer64 {
         ((high_bits << 64) | (self.state >> 64)) as i128
     }
 
+    generic_nonzero_generation_function!(generate_nonzero_u8, generate_u8, NonZeroU8);
+    generic_nonzero_generation_function!(generate_nonzero_u16, generate_u16, NonZeroU16);
+    generic_nonzero_generation_function!(generate_nonzero_u32, generate_u32, NonZeroU32);
+    generic_nonzero_generation_function!(generate_nonzero_u64, generate_u64, NonZeroU64);
+    generic_nonzero_generation_function!(generate_nonzero_u128, generate_u128, NonZeroU128);
+    generic_nonzero_generation_function!(generate_nonzero_usize, generate_usize, NonZeroUsize);
+
     /// Generates a 'random' f64 in the range [0; 1)
     /// and advances the generator state one step.  
     /// Has 53 bits of effective entropy
@@ -117,6 +507,84 @@ impl Lehmer64 {
         (self.generate_u32() >> 8) as f32 * INV_2POW24
     }
 
+    /// Generates a 'random' f64 in the range (0; 1)
+    /// and advances the generator state one step.  
+    /// Has 52 bits of effective entropy, produces the odd multiples of 2^-53.
+    /// The smallest value is 2^-53 and the largest is 1 - 2^-53.
+    #[inline]
+    pub fn generate_f64_open(&mut self) -> f64 {
+        ((self.generate_u64() >> 11) | 1) as f64 * INV_2POW53
+    }
+
+    /// Generates a 'random' f64 in the range [0; 1]
+    /// and advances the generator state at least one step.  
+    /// Produces all 2^53 + 1 multiples of 2^-53 in the range with equal probability.
+    #[inline]
+    pub fn generate_f64_closed(&mut self) -> f64 {
+        self.generate_bounded_u64((1 << 53) + 1) as f64 * INV_2POW53
+    }
+
+    /// Generates a 'random' f32 in the range (0; 1)
+    /// and advances the generator state one step.  
+    /// Has 23 bits of effective entropy, produces the odd multiples of 2^-24.
+    /// The smallest value is 2^-24 and the largest is 1 - 2^-24.
+    #[inline]
+    pub fn generate_f32_open(&mut self) -> f32 {
+        ((self.generate_u32() >> 8) | 1) as f32 * INV_2POW24
+    }
+
+    /// Generates a 'random' f32 in the range [0; 1]
+    /// and advances the generator state at least one step.  
+    /// Produces all 2^24 + 1 multiples of 2^-24 in the range with equal probability.
+    #[inline]
+    pub fn generate_f32_closed(&mut self) -> f32 {
+        self.generate_bounded_u64((1 << 24) + 1) as f32 * INV_2POW24
+    }
+
+    /// Generates a 'random' f64 in the range [lo; hi)
+    /// and advances the generator state one step.  
+    /// Does not overflow for wide ranges like [f64::MIN; f64::MAX).  
+    /// If lo > hi, the result is in the range (hi; lo] instead.
+    /// If lo == hi, returns lo. Returns NaN if either bound is NaN.
+    #[inline]
+    pub fn generate_f64_in_range(&mut self, lo: f64, hi: f64) -> f64 {
+        let u = self.generate_f64();
+        // Interpolating instead of scaling (hi - lo) avoids overflowing to infinity.
+        let result = lo * (1.0 - u) + hi * u;
+        // Rounding can move the result onto hi or slightly past lo.
+        if lo < hi {
+            result.clamp(lo, hi.next_down())
+        } else if lo > hi {
+            result.clamp(hi.next_up(), lo)
+        } else if lo == hi {
+            lo
+        } else {
+            f64::NAN
+        }
+    }
+
+    /// Generates a 'random' f32 in the range [lo; hi)
+    /// and advances the generator state one step.  
+    /// Does not overflow for wide ranges like [f32::MIN; f32::MAX).  
+    /// If lo > hi, the result is in the range (hi; lo] instead.
+    /// If lo == hi, returns lo. Returns NaN if either bound is NaN.
+    #[inline]
+    pub fn generate_f32_in_range(&mut self, lo: f32, hi: f32) -> f32 {
+        let u = self.generate_f32();
+        // Interpolating instead of scaling (hi - lo) avoids overflowing to infinity.
+        let result = lo * (1.0 - u) + hi * u;
+        // Rounding can move the result onto hi or slightly past lo.
+        if lo < hi {
+            result.clamp(lo, hi.next_down())
+        } else if lo > hi {
+            result.clamp(hi.next_up(), lo)
+        } else if lo == hi {
+            lo
+        } else {
+            f32::NAN
+        }
+    }
+
     /// Generates a 'random' boolean and advances the generator state one step.  
     /// Where the distribution of true and false is 50/50.
     #[inline]
@@ -145,29 +613,1950 @@ impl Lehmer64 {
     pub fn generate_weighted_bool(&mut self, chance: f32) -> bool {
         self.generate_f32() < chance
     }
+
+    /// Shuffles the slice in place using the Fisher-Yates algorithm.  
+    /// Every permutation is equally likely.  
+    /// Advances the generator at least slice.len() - 1 steps.
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.generate_range_usize(0, i + 1);
+            slice.swap(i, j);
+        }
+    }
+
+    /// Generates a 'random' char that can be any valid unicode scalar value
+    /// and advances the generator state at least one step.  
+    /// The distribution is uniform over all valid values,
+    /// values in the surrogate range 0xD800..=0xDFFF are rejected and regenerated.
+    pub fn generate_char(&mut self) -> char {
+        loop {
+            if let Some(val) = char::from_u32(self.generate_range_u32(0, char::MAX as u32 + 1)) {
+                return val;
+            }
+        }
+    }
+
+    /// Generates a 'random' ASCII alphanumeric character [0-9A-Za-z]
+    /// and advances the generator state at least one step.  
+    /// The distribution is uniform over all 62 characters.
+    pub fn generate_ascii_alphanumeric(&mut self) -> u8 {
+        const ALPHANUMERIC: &[u8; 62] =
+            b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+        ALPHANUMERIC[self.generate_range_usize(0, ALPHANUMERIC.len())]
+    }
+
+    /// Returns a reference to a uniformly selected element of the slice.  
+    /// Returns `None` if the slice is empty.  
+    /// Advances the generator at least one step if the slice is not empty.
+    pub fn choose<'a, T>(&mut self, slice: &'a [T]) -> Option<&'a T> {
+        let index = self.try_generate_range_usize(0, slice.len())?;
+        slice.get(index)
+    }
+
+    /// Returns a mutable reference to a uniformly selected element of the slice.  
+    /// Returns `None` if the slice is empty.  
+    /// Advances the generator at least one step if the slice is not empty.
+    pub fn choose_mut<'a, T>(&mut self, slice: &'a mut [T]) -> Option<&'a mut T> {
+        let index = self.try_generate_range_usize(0, slice.len())?;
+        slice.get_mut(index)
+    }
+
+    /// Picks a 'random' index of the slice,
+    /// where the chance of each index is proportional to its weight.  
+    /// Takes O(n) time, use `WeightedIndex` to sample the same weights repeatedly.  
+    /// Returns `None` if the slice is empty or all weights are zero.  
+    /// Advances the generator at least one step if a value is returned.
+    ///
+    /// # Panics
+    /// Panics if the sum of the weights overflows a u64.
+    pub fn sample_weighted_index(&mut self, weights: &[u64]) -> Option<usize> {
+        let sum = weights.iter().try_fold(0u64, |acc, &w| acc.checked_add(w));
+        let mut target =
+            self.try_generate_range_u64(0, sum.expect("Sum of weights overflows u64"))?;
+        // Walk the cumulative sum until the target falls into a weight.
+        weights.iter().position(|&w| {
+            let hit = target < w;
+            target = target.wrapping_sub(w);
+            hit
+        })
+    }
+
+    /// Generates a 'random' integer of any primitive type.  
+    /// The value is identical to the one of the type specific generation function,
+    /// this advances the generator one step, or two steps for 128 bit types.
+    ///
+    /// # Examples
+    /// ```
+    /// use fastmath::rng;
+    ///
+    /// let mut rn = rng::Lehmer64::new(0);
+    /// let small: u8 = rn.generate();
+    /// let large = rn.generate::<i128>();
+    /// ```
+    #[inline]
+    pub fn generate<T: BaseInt>(&mut self) -> T {
+        if T::BITS > 64 {
+            let high_bits = T::from_u64(self.generate_u64());
+            (high_bits << T::from_u64(64)) | T::from_u64(self.generate_u64())
+        } else {
+            T::from_u64(self.generate_u64())
+        }
+    }
+
+    /// Fills the destination slice with 'random' integers.  
+    /// Each element is generated like the type specific generation function,
+    /// this advances the generator one step per element,
+    /// or two steps per element for 128 bit types.
+    pub fn fill<T: BaseInt>(&mut self, dest: &mut [T]) {
+        for val in dest {
+            *val = self.generate();
+        }
+    }
+
+    /// Generates an array of 'random' u64 values
+    /// and advances the generator state N steps.
+    ///
+    /// # Examples
+    /// ```
+    /// use fastmath::rng;
+    ///
+    /// let mut rn = rng::Lehmer64::new(0);
+    /// let key: [u64; 4] = rn.generate_array();
+    ///
+    /// let mut reference = rng::Lehmer64::new(0);
+    /// for val in key {
+    ///     assert_eq!(val, reference.generate_u64());
+    /// }
+    ///
+    /// // An empty array does not advance the generator.
+    /// let empty: [u64; 0] = rn.generate_array();
+    /// assert_eq!(empty, []);
+    /// assert_eq!(rn.generate_u64(), reference.generate_u64());
+    /// ```
+    pub fn generate_array<const N: usize>(&mut self) -> [u64; N] {
+        core::array::from_fn(|_| self.generate_u64())
+    }
+
+    /// Returns an infinite iterator yielding successive `generate_u64` outputs.  
+    /// Each item advances the generator state one step.
+    ///
+    /// # Examples
+    /// ```
+    /// use fastmath::rng;
+    ///
+    /// let mut rn = rng::Lehmer64::new(0);
+    /// let mut values = [0u64; 3];
+    /// for (val, generated) in values.iter_mut().zip(rn.iter_u64().take(3)) {
+    ///     *val = generated;
+    /// }
+    ///
+    /// let mut rn = rng::Lehmer64::new(0);
+    /// assert_eq!(values, [rn.generate_u64(), rn.generate_u64(), rn.generate_u64()]);
+    /// ```
+    pub fn iter_u64(&mut self) -> impl Iterator<Item = u64> + '_ {
+        core::iter::repeat_with(move || self.generate_u64())
+    }
+
+    /// Consumes the generator and returns an infinite iterator
+    /// yielding successive `generate_u64` outputs.
+    pub fn into_iter_u64(mut self) -> impl Iterator<Item = u64> {
+        core::iter::repeat_with(move || self.generate_u64())
+    }
+
+    /// Fills the destination slice with 'random' bytes.  
+    /// Each generator step produces 8 bytes, which are written in little-endian order.  
+    /// Advances the generator ceil(dest.len() / 8) steps,
+    /// the unused bytes of the final step are discarded.
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.generate_u64().to_le_bytes());
+        }
+        let remainder = chunks.into_remainder();
+        if !remainder.is_empty() {
+            let bytes = self.generate_u64().to_le_bytes();
+            remainder.copy_from_slice(&bytes[..remainder.len()]);
+        }
+    }
+
+    /// Generates a normally distributed f64 with the specified mean and standard deviation
+    /// using the Box-Muller transform.  
+    /// Each transform produces two values, the second one is cached and returned
+    /// by the next call without advancing the generator.
+    /// Otherwise advances the generator two steps.  
+    /// Returns `mean` if `std_dev` is zero.  
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn generate_gaussian_f64(&mut self, mean: f64, std_dev: f64) -> f64 {
+        let standard_normal = match self.gaussian_spare.take() {
+            Some(spare) => spare,
+            None => {
+                let (first, second) = self.generate_standard_normal_pair();
+                self.gaussian_spare = Some(second);
+                first
+            }
+        };
+        mean + std_dev * standard_normal
+    }
+
+    /// Generates two independent normally distributed f64 with the specified mean
+    /// and standard deviation using a single Box-Muller transform.  
+    /// Does not use or change the value cached by `generate_gaussian_f64`.  
+    /// Advances the generator two steps.  
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn generate_gaussian_pair_f64(&mut self, mean: f64, std_dev: f64) -> (f64, f64) {
+        let (first, second) = self.generate_standard_normal_pair();
+        (mean + std_dev * first, mean + std_dev * second)
+    }
+
+    /// Generates two independent standard normal f64 using the Box-Muller transform.  
+    /// Advances the generator two steps.
+    #[cfg(feature = "libm")]
+    fn generate_standard_normal_pair(&mut self) -> (f64, f64) {
+        // 1 - [0; 1) is in (0; 1], which guards the logarithm against zero.
+        let radius = libm::sqrt(-2.0 * libm::log(1.0 - self.generate_f64()));
+        let (sin, cos) = libm::sincos(core::f64::consts::TAU * self.generate_f64());
+        (radius * cos, radius * sin)
+    }
+
+    /// Generates a poisson distributed u64 with the specified expected value
+    /// using Knuth's multiplicative algorithm.  
+    /// Runtime is O(lambda), so this is only suited for small lambda.
+    /// For lambda above roughly 700 `exp(-lambda)` underflows and the results are no longer accurate.  
+    /// Returns 0 if lambda is zero.  
+    /// Advances the generator result + 1 steps.  
+    /// Requires the `libm` feature.
+    ///
+    /// # Panics
+    /// Panics if lambda is negative or NaN.
+    #[cfg(feature = "libm")]
+    pub fn generate_poisson(&mut self, lambda: f64) -> u64 {
+        assert!(lambda >= 0.0, "Lambda must not be negative");
+        if lambda == 0.0 {
+            return 0;
+        }
+        let limit = libm::exp(-lambda);
+        let mut count = 0;
+        let mut product = self.generate_f64();
+        while product > limit {
+            count += 1;
+            product *= self.generate_f64();
+        }
+        count
+    }
+
+    /// Generate a 'random' bool that is true with a probability of exactly
+    /// numerator / denominator. Always true if numerator >= denominator.  
+    /// Advances the generator at least one step.
+    ///
+    /// # Panics
+    /// Panics if denominator is zero.
+    #[inline]
+    pub fn generate_weighted_bool_ratio(&mut self, numerator: u64, denominator: u64) -> bool {
+        assert!(denominator != 0, "Denominator must not be zero");
+        self.generate_range_u64(0, denominator) < numerator
+    }
+
+    /// Generate a 'random' bool that is true with a probability of exactly 1 / n.
+    /// Always true if n is one.  
+    /// Advances the generator at least one step.
+    ///
+    /// # Panics
+    /// Panics if n is zero.
+    #[inline]
+    pub fn generate_bool_1_in_n(&mut self, n: u64) -> bool {
+        assert!(n != 0, "n must not be zero");
+        self.generate_range_u64(0, n) == 0
+    }
+
+    /// Generates a 'random' u64 in the range [0; range) using Lemire's
+    /// multiply-shift method with rejection, so the result is exactly uniform.  
+    /// Advances the generator at least one step.  
+    /// `range` must not be zero.
+    #[inline]
+    fn generate_bounded_u64(&mut self, range: u64) -> u64 {
+        let mut product = self.generate_u64() as u128 * range as u128;
+        // A power of two range divides 2^64 evenly, so no value has to be rejected.
+        if range & (range - 1) != 0 {
+            let mut low_bits = product as u64;
+            if low_bits < range {
+                let threshold = range.wrapping_neg() % range;
+                while low_bits < threshold {
+                    product = self.generate_u64() as u128 * range as u128;
+                    low_bits = product as u64;
+                }
+            }
+        }
+        (product >> 64) as u64
+    }
+
+    generic_range_function!(generate_range_u8, try_generate_range_u8, u8, u8);
+    generic_range_function!(generate_range_u16, try_generate_range_u16, u16, u16);
+    generic_range_function!(generate_range_u32, try_generate_range_u32, u32, u32);
+    generic_range_function!(generate_range_u64, try_generate_range_u64, u64, u64);
+    generic_range_function!(generate_range_usize, try_generate_range_usize, usize, usize);
+
+    generic_range_function!(generate_range_i8, try_generate_range_i8, i8, u8);
+    generic_range_function!(generate_range_i16, try_generate_range_i16, i16, u16);
+    generic_range_function!(generate_range_i32, try_generate_range_i32, i32, u32);
+    generic_range_function!(generate_range_i64, try_generate_range_i64, i64, u64);
+    generic_range_function!(generate_range_isize, try_generate_range_isize, isize, usize);
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    /// test that two u64 are correctly used when genrating a u128.
-    #[test]
-    fn u128_concatenation_test() {
-        let mut rn = Lehmer64::new(0);
-        let full_integer = rn.generate_u128();
+// Generators are equal if they produce the same future values.
+// This includes the cached gaussian value, which is compared bitwise so Eq holds.
+impl PartialEq for Lehmer64 {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "libm")]
+        if self.gaussian_spare.map(f64::to_bits) != other.gaussian_spare.map(f64::to_bits) {
+            return false;
+        }
+        self.state == other.state
+    }
+}
 
-        // Reset the generator state.
-        let mut rn = Lehmer64::new(0);
-        assert_eq!((full_integer >> 64) as u64, rn.generate_u64());
-        assert_eq!(full_integer as u64, rn.generate_u64());
+impl Eq for Lehmer64 {}
 
-        //Repeat same test for i128
-        let mut rn = Lehmer64::new(0);
-        let full_integer = rn.generate_i128();
+impl core::hash::Hash for Lehmer64 {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.state.hash(state);
+        #[cfg(feature = "libm")]
+        self.gaussian_spare.map(f64::to_bits).hash(state);
+    }
+}
 
-        // Reset the generator state.
-        let mut rn = Lehmer64::new(0);
-        assert_eq!((full_integer >> 64) as i64, rn.generate_i64());
-        assert_eq!(full_integer as i64, rn.generate_i64());
+/// Infinite iterator yielding successive `generate_u64` outputs.  
+/// Lehmer64 is `Copy`, so adaptors that take the iterator by value like `take`
+/// silently iterate over a copy and leave the original generator unchanged.  
+/// Use `by_ref` to advance the generator, or `iter_u64` for an explicit borrow.
+///
+/// # Examples
+/// ```
+/// use fastmath::rng::Lehmer64;
+///
+/// let mut rn = Lehmer64::new(0);
+/// let first = rn.take(3).last();
+/// // The copy was advanced, rn repeats the same values.
+/// assert_eq!(rn.take(3).last(), first);
+/// // by_ref advances rn itself.
+/// assert_eq!(rn.by_ref().take(3).last(), first);
+/// assert_ne!(rn.by_ref().take(3).last(), first);
+/// ```
+impl Iterator for Lehmer64 {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        Some(self.generate_u64())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl rand_core::RngCore for Lehmer64 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.generate_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.generate_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        Lehmer64::fill_bytes(self, dst)
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl rand_core::SeedableRng for Lehmer64 {
+    type Seed = [u8; 16];
+
+    /// Equivalent to `from_bytes`.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_bytes(seed)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// Precomputed sampler for a boolean with a fixed chance of being true.
+pub struct Bernoulli {
+    threshold: u64,
+}
+impl Bernoulli {
+    /// Create a sampler that is true with the specified chance.
+    /// Where chances are expressed as fractions of one. E.g 0.75 is 75 %  
+    /// Returns `None` if the chance is not in the range [0; 1].
+    pub fn new(chance: f64) -> Option<Self> {
+        if !(0.0..=1.0).contains(&chance) {
+            return None;
+        }
+        // Smallest 53 bit integer that is not below chance * 2^53.
+        // Comparing against it is equivalent to `generate_f64() < chance`.
+        let scaled = chance * (1u64 << 53) as f64;
+        let mut threshold = scaled as u64;
+        if (threshold as f64) < scaled {
+            threshold += 1;
+        }
+        Some(Bernoulli { threshold })
+    }
+
+    /// Sample the boolean, advances the generator one step.
+    #[inline]
+    pub fn sample(&self, rng: &mut Lehmer64) -> bool {
+        (rng.generate_u64() >> 11) < self.threshold
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// Precomputed sampler for picking an index out of N weights,
+/// where the chance of each index is proportional to its weight.  
+/// Uses the alias method with exact integer arithmetic,
+/// setup takes O(N) time and sampling takes O(1) time.
+pub struct WeightedIndex<const N: usize> {
+    sum: u64,
+    threshold: [u64; N],
+    alias: [usize; N],
+}
+impl<const N: usize> WeightedIndex<N> {
+    /// Create a sampler for the specified weights.  
+    /// Returns `None` if N is zero or all weights are zero.
+    ///
+    /// # Panics
+    /// Panics if the sum of the weights overflows a u64.
+    pub fn new(weights: [u64; N]) -> Option<Self> {
+        let sum = weights
+            .iter()
+            .try_fold(0u64, |acc, &w| acc.checked_add(w))
+            .expect("Sum of weights overflows u64");
+        if sum == 0 {
+            return None;
+        }
+        // Every index gets a bucket of size sum,
+        // the part of the bucket not covered by its own weight is filled by the alias.
+        let mut scaled: [u128; N] = core::array::from_fn(|i| weights[i] as u128 * N as u128);
+        let mut small = [0usize; N];
+        let mut large = [0usize; N];
+        let (mut small_len, mut large_len) = (0, 0);
+        for (i, &val) in scaled.iter().enumerate() {
+            if val < sum as u128 {
+                small[small_len] = i;
+                small_len += 1;
+            } else {
+                large[large_len] = i;
+                large_len += 1;
+            }
+        }
+        let mut threshold = [sum; N];
+        let mut alias: [usize; N] = core::array::from_fn(|i| i);
+        while small_len > 0 && large_len > 0 {
+            small_len -= 1;
+            let underfull = small[small_len];
+            let overfull = large[large_len - 1];
+            threshold[underfull] = scaled[underfull] as u64;
+            alias[underfull] = overfull;
+            scaled[overfull] -= sum as u128 - scaled[underfull];
+            if scaled[overfull] < sum as u128 {
+                large_len -= 1;
+                small[small_len] = overfull;
+                small_len += 1;
+            }
+        }
+        // The arithmetic is exact, so all remaining buckets are exactly full.
+        Some(WeightedIndex {
+            sum,
+            threshold,
+            alias,
+        })
+    }
+
+    /// Sample an index, advances the generator at least two steps.
+    #[inline]
+    pub fn sample(&self, rng: &mut Lehmer64) -> usize {
+        let bucket = rng.generate_range_usize(0, N);
+        if rng.generate_range_u64(0, self.sum) < self.threshold[bucket] {
+            bucket
+        } else {
+            self.alias[bucket]
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+/// PCG32 (PCG-XSH-RR) PRNG with 64 bits of state and 32 bit output,
+/// but NOT cryptographically secure.  
+/// Supports 2^63 independent streams for the same seed.
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+impl Pcg32 {
+    const MUL_CONSTANT: u64 = 0x5851f42d4c957f2d;
+    /// Initalize a new RNG with the specified seed and stream,
+    /// identical to the reference implementation.  
+    /// Generators with different streams produce independent sequences for the same seed.
+    /// The most significant bit of the stream is ignored.
+    pub fn new(seed: u64, stream: u64) -> Self {
+        let mut new_rng = Pcg32 {
+            state: 0,
+            // The increment has to be odd.
+            increment: (stream << 1) | 1,
+        };
+        new_rng.advance();
+        new_rng.state = new_rng.state.wrapping_add(seed);
+        new_rng.advance();
+        new_rng
+    }
+
+    /// Advances the generator state one step.
+    #[inline(always)]
+    fn advance(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MUL_CONSTANT)
+            .wrapping_add(self.increment);
+    }
+
+    /// Generates a 'random' u32 and advances the generator state one step.
+    #[inline]
+    pub fn generate_u32(&mut self) -> u32 {
+        let old_state = self.state;
+        self.advance();
+        let xorshifted = (((old_state >> 18) ^ old_state) >> 27) as u32;
+        xorshifted.rotate_right((old_state >> 59) as u32)
+    }
+
+    /// Generates a 'random' u64 and advances the generator state two steps.
+    #[inline]
+    pub fn generate_u64(&mut self) -> u64 {
+        let high_bits = self.generate_u32() as u64;
+        (high_bits << 32) | self.generate_u32() as u64
+    }
+
+    derived_generation_functions!();
+}
+
+#[derive(Debug, Copy, Clone)]
+/// Xoshiro256** PRNG with 256 bits of state and 64 bit output,
+/// but NOT cryptographically secure.  
+/// Supports splitting into non-overlapping streams via `jump`.
+pub struct Xoshiro256StarStar {
+    state: [u64; 4],
+}
+impl Xoshiro256StarStar {
+    const JUMP_POLYNOMIAL: [u64; 4] = [
+        0x180ec6d33cfd0aba,
+        0xd5a61266f0c9392c,
+        0xa9582618e03fc9aa,
+        0x39abdc4529b1661c,
+    ];
+    /// Initalize a new RNG with the specified seed.  
+    /// The four state words are the first four outputs of a SplitMix64 generator
+    /// seeded with the seed, as recommended by the reference implementation.
+    pub fn new(seed: u64) -> Self {
+        let mut splitmix = SplitMix64::new(seed);
+        Xoshiro256StarStar {
+            state: core::array::from_fn(|_| splitmix.generate_u64()),
+        }
+    }
+
+    /// Generates a 'random' u64 and advances the generator state one step.
+    #[inline]
+    pub fn generate_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Generates a 'random' u32 from the high bits of `generate_u64`
+    /// and advances the generator state one step.
+    #[inline]
+    pub fn generate_u32(&mut self) -> u32 {
+        (self.generate_u64() >> 32) as u32
+    }
+
+    /// Advances the generator state 2^128 steps.  
+    /// Can be used to generate 2^128 non-overlapping sequences for parallel computations.
+    pub fn jump(&mut self) {
+        let mut jumped = [0u64; 4];
+        for polynomial_word in Self::JUMP_POLYNOMIAL {
+            for bit in 0..u64::BITS {
+                if polynomial_word & (1 << bit) != 0 {
+                    for (jumped_word, state_word) in jumped.iter_mut().zip(self.state) {
+                        *jumped_word ^= state_word;
+                    }
+                }
+                self.generate_u64();
+            }
+        }
+        self.state = jumped;
+    }
+
+    derived_generation_functions!();
+}
+
+#[derive(Debug, Copy, Clone)]
+/// SplitMix64 PRNG with 64 bits of state and 64 bit output,
+/// but NOT cryptographically secure.  
+/// Primarily useful for seeding other generators from a single u64,
+/// since similar seeds produce unrelated sequences.
+pub struct SplitMix64 {
+    state: u64,
+}
+impl SplitMix64 {
+    const GOLDEN_GAMMA: u64 = GOLDEN_RATIO_64;
+    /// Initalize a new RNG with the specified seed.  
+    /// Where the seed is the intial internal state, any seed including zero is strong.
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    /// Generates a 'random' u64 and advances the generator state one step.
+    #[inline]
+    pub fn generate_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(Self::GOLDEN_GAMMA);
+        Self::mix(self.state)
+    }
+
+    /// The SplitMix64 output function, a bijective avalanche finalizer.
+    #[inline]
+    const fn mix(mut z: u64) -> u64 {
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Generates a 'random' u32 from the high bits of `generate_u64`
+    /// and advances the generator state one step.
+    #[inline]
+    pub fn generate_u32(&mut self) -> u32 {
+        (self.generate_u64() >> 32) as u32
+    }
+
+    derived_generation_functions!();
+}
+
+impl_random_generator!(Lehmer64, Pcg32, Xoshiro256StarStar, SplitMix64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile time tests to ensure generators can be created in const contexts.
+    const _: Lehmer64 = Lehmer64::new(12345);
+    const _: () = assert!(
+        Lehmer64::new(0).state()
+            == Lehmer64::DEFAULT_SEED
+                .wrapping_mul(Lehmer64::MUL_CONSTANT)
+                .wrapping_mul(Lehmer64::MUL_CONSTANT)
+    );
+    const _: () = assert!(
+        Lehmer64::from_bytes([1; 16]).state()
+            == Lehmer64::new(u128::from_le_bytes([1; 16])).state()
+    );
+    const _: () = {
+        let mut rn = Lehmer64::from_state(1);
+        rn.advance_by(2);
+        assert!(rn.state() == Lehmer64::MUL_CONSTANT * Lehmer64::MUL_CONSTANT);
+    };
+
+    /// Returns the state that produces the specified state after one generator step.
+    fn previous_state(state: u128) -> u128 {
+        // Newton iteration for the inverse of the odd multiplier mod 2^128.
+        let mut inverse = Lehmer64::MUL_CONSTANT;
+        for _ in 0..7 {
+            inverse = inverse
+                .wrapping_mul(2u128.wrapping_sub(Lehmer64::MUL_CONSTANT.wrapping_mul(inverse)));
+        }
+        state.wrapping_mul(inverse)
+    }
+
+    /// test that two u64 are correctly used when genrating a u128.
+    #[test]
+    fn u128_concatenation_test() {
+        let mut rn = Lehmer64::new(0);
+        let full_integer = rn.generate_u128();
+
+        // Reset the generator state.
+        let mut rn = Lehmer64::new(0);
+        assert_eq!((full_integer >> 64) as u64, rn.generate_u64());
+        assert_eq!(full_integer as u64, rn.generate_u64());
+
+        //Repeat same test for i128
+        let mut rn = Lehmer64::new(0);
+        let full_integer = rn.generate_i128();
+
+        // Reset the generator state.
+        let mut rn = Lehmer64::new(0);
+        assert_eq!((full_integer >> 64) as i64, rn.generate_i64());
+        assert_eq!(full_integer as i64, rn.generate_i64());
+    }
+
+    /// Test that ranged generation is close to uniform and reaches both boundaries.
+    #[test]
+    fn range_u64_uniformity_test() {
+        const LO: u64 = 10;
+        const HI: u64 = 17;
+        const SAMPLES: u32 = 70_000;
+        let mut rn = Lehmer64::new(0);
+        let mut counts = [0u32; (HI - LO) as usize];
+        for _ in 0..SAMPLES {
+            let val = rn.generate_range_u64(LO, HI);
+            assert!((LO..HI).contains(&val), "Value {} out of range", val);
+            counts[(val - LO) as usize] += 1;
+        }
+        // Every bucket should be within 5 % of the expected count.
+        let expected = SAMPLES / (HI - LO) as u32;
+        for (offset, count) in counts.iter().enumerate() {
+            assert!(
+                count.abs_diff(expected) < expected / 20,
+                "Bucket {} has {} hits, expected about {}",
+                offset as u64 + LO,
+                count,
+                expected
+            );
+        }
+        // Both boundaries are reachable.
+        assert!(counts[0] > 0, "lo was never generated");
+        assert!(counts[counts.len() - 1] > 0, "hi - 1 was never generated");
+    }
+
+    /// Test ranged generation on 1..7 against a uniform distribution with a chi-squared test.
+    #[test]
+    fn range_chi_squared_test() {
+        const SAMPLES: u32 = 60_000;
+        // Critical value for 5 degrees of freedom at p = 0.001
+        const CRITICAL_VALUE: f64 = 20.515;
+        let mut rn = Lehmer64::new(0);
+        let mut counts = [0u32; 6];
+        for _ in 0..SAMPLES {
+            counts[rn.generate_range_u8(1, 7) as usize - 1] += 1;
+        }
+        let expected = SAMPLES as f64 / counts.len() as f64;
+        let chi_squared: f64 = counts
+            .iter()
+            .map(|&count| (count as f64 - expected) * (count as f64 - expected) / expected)
+            .sum();
+        assert!(
+            chi_squared < CRITICAL_VALUE,
+            "Chi-squared statistic {} exceeds {}",
+            chi_squared,
+            CRITICAL_VALUE
+        );
+    }
+
+    /// Test that ranged generation stays in [lo; hi) for all types.
+    #[test]
+    fn range_bounds_test() {
+        let mut rn = Lehmer64::new(0);
+        for _ in 0..10_000 {
+            assert!((3..250).contains(&rn.generate_range_u8(3, 250)));
+            assert!((3..1000).contains(&rn.generate_range_u16(3, 1000)));
+            assert!((3..100_000).contains(&rn.generate_range_u32(3, 100_000)));
+            assert!((3..u64::MAX - 3).contains(&rn.generate_range_u64(3, u64::MAX - 3)));
+            assert!((3..1000).contains(&rn.generate_range_usize(3, 1000)));
+            assert!((-100..100).contains(&rn.generate_range_i8(-100, 100)));
+            assert!((-1000..-10).contains(&rn.generate_range_i16(-1000, -10)));
+            assert!((-100_000..3).contains(&rn.generate_range_i32(-100_000, 3)));
+            assert!((i64::MIN..0).contains(&rn.generate_range_i64(i64::MIN, 0)));
+            assert!((-1000..1000).contains(&rn.generate_range_isize(-1000, 1000)));
+            let val = rn.generate_f64_in_range(-2.5, 7.25);
+            assert!((-2.5..7.25).contains(&val), "Value {} out of range", val);
+        }
+    }
+
+    /// Test ranged generation edge cases.
+    #[test]
+    fn range_u64_edge_case_test() {
+        let mut rn = Lehmer64::new(0);
+        // Single value range
+        assert_eq!(rn.generate_range_u64(5, 6), 5);
+        // Power of two range
+        for _ in 0..1000 {
+            assert!(rn.generate_range_u64(0, 8) < 8);
+        }
+        // Full range
+        assert!(rn.try_generate_range_u64(0, u64::MAX).is_some());
+        // Empty ranges
+        assert_eq!(rn.try_generate_range_u64(3, 3), None);
+        assert_eq!(rn.try_generate_range_u64(4, 3), None);
+    }
+
+    /// Test that an empty range panics.
+    #[test]
+    #[should_panic]
+    fn range_u64_empty_panic_test() {
+        let mut rn = Lehmer64::new(0);
+        rn.generate_range_u64(7, 7);
+    }
+
+    /// Test that signed ranges straddling zero are handled correctly.
+    #[test]
+    fn range_signed_test() {
+        let mut rn = Lehmer64::new(0);
+        let mut seen = [false; 10];
+        for _ in 0..1000 {
+            let val = rn.generate_range_i32(-5, 5);
+            assert!((-5..5).contains(&val), "Value {} out of range", val);
+            seen[(val + 5) as usize] = true;
+        }
+        assert!(
+            seen.iter().all(|&x| x),
+            "Not all values in [-5; 5) generated"
+        );
+
+        // Full width range reaches both extremes.
+        let mut seen_min = false;
+        let mut seen_max = false;
+        for _ in 0..10_000 {
+            let val = rn.generate_range_i8(i8::MIN, i8::MAX);
+            seen_min |= val == i8::MIN;
+            seen_max |= val == i8::MAX - 1;
+        }
+        assert!(seen_min, "i8::MIN was never generated");
+        assert!(seen_max, "i8::MAX - 1 was never generated");
+
+        assert_eq!(rn.generate_range_i64(i64::MAX - 1, i64::MAX), i64::MAX - 1);
+        assert_eq!(rn.generate_range_isize(-1, 0), -1);
+        assert_eq!(rn.try_generate_range_i16(0, -1), None);
+    }
+
+    /// Test that filling bytes matches the corresponding u64 generation.
+    #[test]
+    fn fill_bytes_test() {
+        let mut rn = Lehmer64::new(0);
+        let mut buffer = [0u8; 17];
+        rn.fill_bytes(&mut buffer);
+        let state_after_fill = rn.state;
+
+        // Reset the generator state.
+        let mut rn = Lehmer64::new(0);
+        let mut expected = [0u8; 24];
+        for chunk in expected.chunks_exact_mut(8) {
+            chunk.copy_from_slice(&rn.generate_u64().to_le_bytes());
+        }
+        assert_eq!(buffer, expected[..17]);
+        // Three generator steps were taken.
+        assert_eq!(state_after_fill, rn.state);
+
+        // An empty slice does not advance the generator.
+        rn.fill_bytes(&mut []);
+        assert_eq!(state_after_fill, rn.state);
+    }
+
+    /// Test that generic generation matches the type specific functions.
+    #[test]
+    fn generate_generic_test() {
+        let mut rn = Lehmer64::new(0);
+        let mut reference = Lehmer64::new(0);
+        for _ in 0..100 {
+            assert_eq!(rn.generate::<u8>(), reference.generate_u8());
+            assert_eq!(rn.generate::<u16>(), reference.generate_u16());
+            assert_eq!(rn.generate::<u32>(), reference.generate_u32());
+            assert_eq!(rn.generate::<u64>(), reference.generate_u64());
+            assert_eq!(rn.generate::<u128>(), reference.generate_u128());
+            assert_eq!(rn.generate::<usize>(), reference.generate_usize());
+            assert_eq!(rn.generate::<i8>(), reference.generate_i8());
+            assert_eq!(rn.generate::<i16>(), reference.generate_i16());
+            assert_eq!(rn.generate::<i32>(), reference.generate_i32());
+            assert_eq!(rn.generate::<i64>(), reference.generate_i64());
+            assert_eq!(rn.generate::<i128>(), reference.generate_i128());
+            assert_eq!(rn.generate::<isize>(), reference.generate_isize());
+        }
+        assert_eq!(rn.state, reference.state);
+    }
+
+    /// Test byte filling of longer and word aligned buffers.
+    #[test]
+    fn fill_bytes_length_test() {
+        let mut rn = Lehmer64::new(0);
+        let mut buffer = [0u8; 100];
+        rn.fill_bytes(&mut buffer);
+        assert_ne!(buffer, [0u8; 100]);
+        assert_ne!(buffer, [u8::MAX; 100]);
+
+        // Reset the generator state.
+        let mut rn = Lehmer64::new(0);
+        let mut reference = Lehmer64::new(0);
+        let mut buffer = [0u8; 32];
+        rn.fill_bytes(&mut buffer);
+        for chunk in buffer.chunks_exact(8) {
+            assert_eq!(chunk, reference.generate_u64().to_le_bytes());
+        }
+        assert_eq!(rn.state, reference.state);
+    }
+
+    /// Test that weighted index sampling follows the weights and skips zero weights.
+    #[test]
+    fn sample_weighted_index_test() {
+        const SAMPLES: u32 = 10_000;
+        let mut rn = Lehmer64::new(0);
+        let mut counts = [0u32; 3];
+        for _ in 0..SAMPLES {
+            counts[rn.sample_weighted_index(&[1, 0, 99]).unwrap()] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert!(counts[0] < 200, "Index 0 chosen {} times", counts[0]);
+
+        assert_eq!(rn.sample_weighted_index(&[]), None);
+        assert_eq!(rn.sample_weighted_index(&[0, 0, 0]), None);
+        assert_eq!(rn.sample_weighted_index(&[0, 0, u64::MAX]), Some(2));
+    }
+
+    /// Test that the alias method sampler matches the weights.
+    #[test]
+    fn weighted_index_test() {
+        const SAMPLES: u32 = 100_000;
+        const WEIGHTS: [u64; 6] = [1, 0, 99, 20, 0, 80];
+        const WEIGHT_SUM: u64 = 200;
+        let sampler = WeightedIndex::new(WEIGHTS).unwrap();
+        let mut rn = Lehmer64::new(0);
+        let mut counts = [0u32; 6];
+        for _ in 0..SAMPLES {
+            counts[sampler.sample(&mut rn)] += 1;
+        }
+        for (i, (&count, &weight)) in counts.iter().zip(WEIGHTS.iter()).enumerate() {
+            let expected = (SAMPLES as u64 * weight / WEIGHT_SUM) as u32;
+            assert!(
+                count.abs_diff(expected) <= expected / 10 + 30,
+                "Index {} chosen {} times, expected about {}",
+                i,
+                count,
+                expected
+            );
+            if weight == 0 {
+                assert_eq!(count, 0, "Zero weight index {} was chosen", i);
+            }
+        }
+
+        assert!(WeightedIndex::new([0u64; 0]).is_none());
+        assert!(WeightedIndex::new([0u64; 3]).is_none());
+        let single = WeightedIndex::new([0, 5, 0]).unwrap();
+        assert!((0..100).all(|_| single.sample(&mut rn) == 1));
+    }
+
+    /// Test that the iterators yield the same values as direct generation.
+    #[test]
+    fn iter_u64_test() {
+        let mut rn = Lehmer64::new(0);
+        let mut reference = Lehmer64::new(0);
+        for val in rn.iter_u64().take(100) {
+            assert_eq!(val, reference.generate_u64());
+        }
+        // The borrowing iterator advanced the original generator.
+        assert_eq!(rn.state, reference.state);
+
+        for val in rn.into_iter_u64().take(100) {
+            assert_eq!(val, reference.generate_u64());
+        }
+    }
+
+    /// Test that the generator itself yields the same values as direct generation.
+    #[test]
+    fn iterator_test() {
+        let mut rn = Lehmer64::new(0);
+        let mut reference = Lehmer64::new(0);
+        let mut collected = [0u64; 3];
+        for (val, generated) in collected.iter_mut().zip(rn.by_ref().take(3)) {
+            *val = generated;
+        }
+        assert_eq!(
+            collected,
+            [
+                reference.generate_u64(),
+                reference.generate_u64(),
+                reference.generate_u64()
+            ]
+        );
+        assert_eq!(rn.state, reference.state);
+
+        assert_eq!(rn.next(), Some(reference.generate_u64()));
+        assert_eq!(rn.size_hint(), (usize::MAX, None));
+
+        // Taking by value iterates over a copy and does not advance the generator.
+        let state = rn.state;
+        let first: [u64; 3] = core::array::from_fn(|_| reference.generate_u64());
+        for (val, generated) in first.iter().zip(rn.take(3)) {
+            assert_eq!(*val, generated);
+        }
+        assert_eq!(rn.state, state);
+        for (val, generated) in first.iter().zip(rn.take(3)) {
+            assert_eq!(*val, generated);
+        }
+    }
+
+    /// Test that shuffling produces a reproducible permutation.
+    #[test]
+    fn shuffle_test() {
+        let original: [u32; 32] = core::array::from_fn(|i| (i as u32) / 2);
+        let mut rn = Lehmer64::new(0);
+        let mut shuffled = original;
+        rn.shuffle(&mut shuffled);
+        assert_ne!(shuffled, original);
+
+        // Sorting recovers the original multiset.
+        let mut sorted = shuffled;
+        sorted.sort_unstable();
+        assert_eq!(sorted, original);
+
+        // The same seed reproduces the same permutation.
+        let mut rn = Lehmer64::new(0);
+        let mut reshuffled = original;
+        rn.shuffle(&mut reshuffled);
+        assert_eq!(shuffled, reshuffled);
+
+        // Empty and single element slices are no-ops.
+        let state_before = rn.state;
+        rn.shuffle::<u8>(&mut []);
+        let mut single = [42u8];
+        rn.shuffle(&mut single);
+        assert_eq!(single, [42]);
+        assert_eq!(rn.state, state_before);
+    }
+
+    /// Test that all permutations of a short slice are equally likely.
+    #[test]
+    fn shuffle_uniformity_test() {
+        const SAMPLES: u32 = 10_000;
+        const PERMUTATIONS: u32 = 120;
+        let mut rn = Lehmer64::new(0);
+        // Permutations are indexed by reading them as base 5 numbers.
+        let mut counts = [0u32; 5usize.pow(5)];
+        for _ in 0..SAMPLES {
+            let mut values = [0usize, 1, 2, 3, 4];
+            rn.shuffle(&mut values);
+            let index = values.iter().fold(0, |acc, &x| acc * 5 + x);
+            counts[index] += 1;
+        }
+        let seen = counts.iter().filter(|&&count| count != 0).count();
+        assert_eq!(seen as u32, PERMUTATIONS);
+        // Every permutation should be within 50 % of the expected count.
+        let expected = SAMPLES / PERMUTATIONS;
+        for count in counts.iter().filter(|&&count| count != 0) {
+            assert!(
+                count.abs_diff(expected) < expected / 2,
+                "Permutation has {} hits, expected about {}",
+                count,
+                expected
+            );
+        }
+    }
+
+    /// Test that every element can be chosen and empty slices return None.
+    #[test]
+    fn choose_test() {
+        let mut rn = Lehmer64::new(0);
+        let values = [10u8, 20, 30, 40];
+        let mut seen = [false; 4];
+        for _ in 0..1000 {
+            let chosen = rn.choose(&values).unwrap();
+            seen[(*chosen / 10 - 1) as usize] = true;
+        }
+        assert!(seen.iter().all(|&x| x), "Not every element was chosen");
+
+        let mut counts = [0u32; 4];
+        for _ in 0..1000 {
+            *rn.choose_mut(&mut counts).unwrap() += 1;
+        }
+        assert!(
+            counts.iter().all(|&x| x > 0),
+            "Not every element was chosen"
+        );
+        assert_eq!(counts.iter().sum::<u32>(), 1000);
+
+        let empty: [u8; 0] = [];
+        assert_eq!(rn.choose(&empty), None);
+        assert_eq!(rn.choose_mut(&mut []), None::<&mut u8>);
+    }
+
+    /// Test that gaussian samples have the requested mean and variance.
+    #[cfg(feature = "libm")]
+    #[test]
+    fn gaussian_f64_test() {
+        const SAMPLES: usize = 100_000;
+        const MEAN: f64 = 3.5;
+        const STD_DEV: f64 = 2.0;
+        let mut rn = Lehmer64::new(0);
+        let mut sum = 0.0;
+        let mut sum_of_squares = 0.0;
+        for _ in 0..SAMPLES {
+            let val = rn.generate_gaussian_f64(MEAN, STD_DEV);
+            assert!(val.is_finite());
+            sum += val;
+            sum_of_squares += val * val;
+        }
+        let sample_mean = sum / SAMPLES as f64;
+        let sample_variance = sum_of_squares / SAMPLES as f64 - sample_mean * sample_mean;
+        assert!(
+            (sample_mean - MEAN).abs() < 0.05,
+            "Sample mean {} too far from {}",
+            sample_mean,
+            MEAN
+        );
+        assert!(
+            (sample_variance - STD_DEV * STD_DEV).abs() < 0.1,
+            "Sample variance {} too far from {}",
+            sample_variance,
+            STD_DEV * STD_DEV
+        );
+
+        // A standard deviation of zero always returns the mean.
+        for _ in 0..10 {
+            assert_eq!(rn.generate_gaussian_f64(MEAN, 0.0), MEAN);
+        }
+    }
+
+    /// Test that the second value of a transform is cached.
+    #[cfg(feature = "libm")]
+    #[test]
+    fn gaussian_f64_cache_test() {
+        let mut rn = Lehmer64::new(0);
+        rn.generate_gaussian_f64(0.0, 1.0);
+        let state_after_transform = rn.state;
+        rn.generate_gaussian_f64(0.0, 1.0);
+        assert_eq!(rn.state, state_after_transform);
+        rn.generate_gaussian_f64(0.0, 1.0);
+        assert_ne!(rn.state, state_after_transform);
+    }
+
+    /// Test that gaussian pairs have the requested mean and standard deviation.
+    #[cfg(feature = "libm")]
+    #[test]
+    fn gaussian_pair_f64_test() {
+        const PAIRS: usize = 5_000;
+        const MEAN: f64 = 10.0;
+        const STD_DEV: f64 = 2.0;
+        let mut rn = Lehmer64::new(0);
+        let mut sum = 0.0;
+        let mut sum_of_squares = 0.0;
+        for _ in 0..PAIRS {
+            let (first, second) = rn.generate_gaussian_pair_f64(MEAN, STD_DEV);
+            sum += first + second;
+            sum_of_squares += first * first + second * second;
+        }
+        let samples = (2 * PAIRS) as f64;
+        let sample_mean = sum / samples;
+        let sample_std_dev = (sum_of_squares / samples - sample_mean * sample_mean).sqrt();
+        assert!(
+            (sample_mean - MEAN).abs() < MEAN * 0.03,
+            "Sample mean {} too far from {}",
+            sample_mean,
+            MEAN
+        );
+        assert!(
+            (sample_std_dev - STD_DEV).abs() < STD_DEV * 0.03,
+            "Sample standard deviation {} too far from {}",
+            sample_std_dev,
+            STD_DEV
+        );
+
+        // The pair matches two consecutive cached values.
+        let mut pair_rn = Lehmer64::new(0);
+        let mut cached_rn = Lehmer64::new(0);
+        let (first, second) = pair_rn.generate_gaussian_pair_f64(MEAN, STD_DEV);
+        assert_eq!(first, cached_rn.generate_gaussian_f64(MEAN, STD_DEV));
+        assert_eq!(second, cached_rn.generate_gaussian_f64(MEAN, STD_DEV));
+        assert_eq!(pair_rn.state, cached_rn.state);
+    }
+
+    /// Test that poisson samples have mean and variance lambda.
+    #[cfg(feature = "libm")]
+    #[test]
+    fn poisson_test() {
+        const SAMPLES: usize = 100_000;
+        const LAMBDA: f64 = 4.0;
+        let mut rn = Lehmer64::new(0);
+        let mut sum = 0.0;
+        let mut sum_of_squares = 0.0;
+        for _ in 0..SAMPLES {
+            let val = rn.generate_poisson(LAMBDA) as f64;
+            sum += val;
+            sum_of_squares += val * val;
+        }
+        let sample_mean = sum / SAMPLES as f64;
+        let sample_variance = sum_of_squares / SAMPLES as f64 - sample_mean * sample_mean;
+        assert!(
+            (sample_mean - LAMBDA).abs() < 0.05,
+            "Sample mean {} too far from {}",
+            sample_mean,
+            LAMBDA
+        );
+        assert!(
+            (sample_variance - LAMBDA).abs() < 0.1,
+            "Sample variance {} too far from {}",
+            sample_variance,
+            LAMBDA
+        );
+
+        // Lambda of zero always returns zero without advancing the generator.
+        let state = rn.state;
+        assert_eq!(rn.generate_poisson(0.0), 0);
+        assert_eq!(rn.state, state);
+    }
+
+    /// Test that negative lambda is rejected.
+    #[cfg(feature = "libm")]
+    #[test]
+    #[should_panic(expected = "Lambda must not be negative")]
+    fn poisson_negative_lambda_test() {
+        Lehmer64::new(0).generate_poisson(-1.0);
+    }
+
+    /// Test that jumping ahead matches advancing step by step.
+    #[test]
+    fn advance_by_test() {
+        let mut rn = Lehmer64::new(0);
+        let mut reference = Lehmer64::new(0);
+        rn.advance_by(0);
+        assert_eq!(rn.state, reference.state);
+
+        rn.advance_by(1000);
+        for _ in 0..1000 {
+            reference.advance();
+        }
+        assert_eq!(rn.state, reference.state);
+
+        rn.advance_by(1);
+        reference.advance();
+        assert_eq!(rn.generate_u64(), reference.generate_u64());
+    }
+
+    /// Test that jumping ahead lands on the same position as sequential generation.
+    #[test]
+    fn advance_by_sequence_test() {
+        let mut sequential = Lehmer64::new(0);
+        let values: [u64; 1000] = core::array::from_fn(|_| sequential.generate_u64());
+
+        let mut jumped = Lehmer64::new(0);
+        jumped.advance_by(500);
+        assert_eq!(jumped.generate_u64(), values[500]);
+        jumped.advance_by(498);
+        assert_eq!(jumped.generate_u64(), values[999]);
+    }
+
+    /// Test that jump matches sequential generation.
+    #[test]
+    fn jump_test() {
+        let mut sequential = Lehmer64::new(0);
+        let values: [u64; 1000] = core::array::from_fn(|_| sequential.generate_u64());
+
+        let mut jumped = Lehmer64::new(0);
+        jumped.jump(500);
+        assert_eq!(jumped.generate_u64(), values[500]);
+        jumped.jump(0);
+        assert_eq!(jumped.generate_u64(), values[501]);
+    }
+
+    /// Test that split streams are distinct and do not look correlated.
+    #[test]
+    fn split_test() {
+        const SAMPLES: usize = 1000;
+        let mut parent = Lehmer64::new(0);
+        let mut first_child = parent.split();
+        let mut second_child = parent.split();
+        // Splitting a child must not reproduce the stream of its parent.
+        let mut grandchild = first_child.split();
+        assert_ne!(first_child.state, grandchild.state);
+        assert_ne!(first_child.state, parent.state);
+
+        let mut reference = Lehmer64::new(0);
+        reference.advance_by(2);
+        assert_eq!(parent.state, reference.state);
+
+        let streams: [[u64; SAMPLES]; 4] = [
+            core::array::from_fn(|_| parent.generate_u64()),
+            core::array::from_fn(|_| first_child.generate_u64()),
+            core::array::from_fn(|_| second_child.generate_u64()),
+            core::array::from_fn(|_| grandchild.generate_u64()),
+        ];
+        for (a, b) in [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)] {
+            assert!(
+                streams[a].iter().all(|x| !streams[b].contains(x)),
+                "Streams {} and {} share values",
+                a,
+                b
+            );
+            // Uncorrelated values differ in half of their bits on average.
+            let differing_bits: u32 = streams[a]
+                .iter()
+                .zip(streams[b].iter())
+                .map(|(x, y)| (x ^ y).count_ones())
+                .sum();
+            let average = differing_bits as f64 / SAMPLES as f64;
+            assert!(
+                (average - 32.0).abs() < 1.0,
+                "Streams {} and {} differ in {} bits on average",
+                a,
+                b,
+                average
+            );
+        }
+    }
+
+    /// Test that a restored state continues the original sequence.
+    #[test]
+    fn state_round_trip_test() {
+        let mut rn = Lehmer64::new(0);
+        for _ in 0..10 {
+            rn.generate_u64();
+        }
+        let checkpoint = rn.state();
+        let mut continuation = [0u64; 10];
+        for val in &mut continuation {
+            *val = rn.generate_u64();
+        }
+
+        let mut restored = Lehmer64::from_state(checkpoint);
+        assert_eq!(restored.state(), checkpoint);
+        for val in continuation {
+            assert_eq!(restored.generate_u64(), val);
+        }
+
+        // No weak seed replacement or shuffling is applied.
+        assert_eq!(Lehmer64::from_state(1).generate_u64(), 0);
+        assert_eq!(Lehmer64::from_state(1).state(), 1);
+    }
+
+    /// Test that a zero state is rejected.
+    #[test]
+    #[should_panic(expected = "State must not be zero")]
+    fn from_state_zero_panic_test() {
+        Lehmer64::from_state(0);
+    }
+
+    /// Test that pair generation is deterministic and the first value matches generate_u64.
+    #[test]
+    fn generate_u64_pair_test() {
+        let mut rn = Lehmer64::new(0);
+        let mut reference = Lehmer64::new(0);
+        let mut repeat = Lehmer64::new(0);
+        for _ in 0..100 {
+            let (high, low) = rn.generate_u64_pair();
+            assert_eq!(high, reference.generate_u64());
+            assert_eq!(SplitMix64::mix(reference.state as u64), low);
+            assert_eq!(repeat.generate_u64_pair(), (high, low));
+        }
+        assert_eq!(rn.state, reference.state);
+
+        let mut rn = Lehmer64::new(0);
+        assert_eq!(
+            rn.generate_u64_pair(),
+            (0x064577751fa75998, 0x13b8504be1748682)
+        );
+    }
+
+    /// Test checkpointing a simulation and replaying it from the saved state.
+    #[test]
+    fn state_checkpoint_test() {
+        let mut rn = Lehmer64::new(0);
+        for _ in 0..100 {
+            rn.generate_u64();
+        }
+        let checkpoint = rn.state();
+        let first_run: [u64; 50] = core::array::from_fn(|_| rn.generate_u64());
+
+        let mut restored = Lehmer64::from_state(checkpoint);
+        let second_run: [u64; 50] = core::array::from_fn(|_| restored.generate_u64());
+        assert_eq!(first_run, second_run);
+        assert_eq!(rn.state(), restored.state());
+    }
+
+    /// Simple FNV-1a hasher to check Hash without std.
+    struct FnvHasher(u64);
+    impl core::hash::Hasher for FnvHasher {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+        fn write(&mut self, bytes: &[u8]) {
+            for &byte in bytes {
+                self.0 = (self.0 ^ byte as u64).wrapping_mul(0x100000001b3);
+            }
+        }
+    }
+
+    fn fnv_hash(rn: &Lehmer64) -> u64 {
+        use core::hash::{Hash, Hasher};
+        let mut hasher = FnvHasher(0xcbf29ce484222325);
+        rn.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Test that generators compare and hash equal exactly if their state is equal.
+    #[test]
+    fn eq_hash_test() {
+        let mut a = Lehmer64::new(0);
+        let mut b = Lehmer64::new(0);
+        assert_eq!(a, b);
+        a.generate_u64();
+        assert_ne!(a, b);
+        b.generate_u64();
+        assert_eq!(a, b);
+        assert_eq!(fnv_hash(&a), fnv_hash(&b));
+
+        // Jumping ahead reaches an equal generator.
+        a.advance_by(100);
+        assert_ne!(a, b);
+        assert_ne!(fnv_hash(&a), fnv_hash(&b));
+        for _ in 0..100 {
+            b.generate_u64();
+        }
+        assert_eq!(a, b);
+        assert_eq!(fnv_hash(&a), fnv_hash(&b));
+    }
+
+    /// Test that a cached gaussian value is part of the equality.
+    #[cfg(feature = "libm")]
+    #[test]
+    fn eq_gaussian_spare_test() {
+        let mut a = Lehmer64::new(0);
+        a.generate_gaussian_f64(0.0, 1.0);
+        let b = Lehmer64::from_state(a.state());
+        assert_ne!(a, b);
+        a.generate_gaussian_f64(0.0, 1.0);
+        assert_eq!(a, b);
+        assert_eq!(fnv_hash(&a), fnv_hash(&b));
+    }
+
+    /// Test that the rand_core traits use the native generation functions.
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn rand_core_test() {
+        use rand::distr::{Distribution, StandardUniform};
+        use rand_core::{RngCore, SeedableRng};
+
+        let mut reference = Lehmer64::new(0);
+        let mut seeded = Lehmer64::from_seed([0; 16]);
+        let rng: &mut dyn RngCore = &mut seeded;
+        let sample: u64 = StandardUniform.sample(rng);
+        assert_eq!(sample, reference.generate_u64());
+        assert_eq!(rng.next_u32(), reference.generate_u32());
+        assert_eq!(rng.next_u64(), reference.generate_u64());
+
+        let mut bytes = [0u8; 20];
+        let mut reference_bytes = [0u8; 20];
+        rng.fill_bytes(&mut bytes);
+        reference.fill_bytes(&mut reference_bytes);
+        assert_eq!(bytes, reference_bytes);
+    }
+
+    /// Test that 64 bit seeds are expanded with SplitMix64.
+    #[test]
+    fn from_seed_u64_test() {
+        assert_eq!(
+            Lehmer64::from_seed_u64(0).state,
+            Lehmer64::new(0xe220a8397b1dcdaf6e789e6aa1b965f4).state
+        );
+        let mut first = Lehmer64::from_seed_u64(1);
+        let mut second = Lehmer64::from_seed_u64(2);
+        assert!((0..100).all(|_| first.generate_u64() != second.generate_u64()));
+    }
+
+    /// Test that byte seeds are equivalent to integer seeds.
+    #[test]
+    fn from_bytes_test() {
+        assert_eq!(Lehmer64::from_bytes([0; 16]).state, Lehmer64::new(0).state);
+        for seed in [1, 0x1234, u128::MAX, 0x0123456789abcdef0011223344556677] {
+            assert_eq!(
+                Lehmer64::from_bytes(u128::to_le_bytes(seed)).state,
+                Lehmer64::new(seed).state,
+                "Failed with seed={:#x}",
+                seed
+            );
+        }
+        let mut bytes = [0u8; 16];
+        bytes[0] = 1;
+        assert_eq!(Lehmer64::from_bytes(bytes).state, Lehmer64::new(1).state);
+    }
+
+    /// Test the endpoints of the open and closed float ranges.
+    #[test]
+    fn float_interval_test() {
+        let mut rn = Lehmer64::new(0);
+        for _ in 0..100_000 {
+            let val = rn.generate_f64_open();
+            assert!(val > 0.0 && val < 1.0, "Value {} out of range", val);
+            let val = rn.generate_f32_open();
+            assert!(val > 0.0 && val < 1.0, "Value {} out of range", val);
+            let val = rn.generate_f64_closed();
+            assert!((0.0..=1.0).contains(&val), "Value {} out of range", val);
+            let val = rn.generate_f32_closed();
+            assert!((0.0..=1.0).contains(&val), "Value {} out of range", val);
+        }
+
+        // Largest and smallest possible underlying integers
+        let max_state = previous_state(u128::MAX);
+        assert_eq!(Lehmer64::from_state(max_state).generate_u64(), u64::MAX);
+
+        // The smallest valid state produces a zero output.
+        assert_eq!(Lehmer64::from_state(1).generate_f64_open(), INV_2POW53);
+        assert_eq!(Lehmer64::from_state(1).generate_f32_open(), INV_2POW24);
+        assert_eq!(
+            Lehmer64::from_state(max_state).generate_f64_open(),
+            1.0 - INV_2POW53
+        );
+        assert_eq!(
+            Lehmer64::from_state(max_state).generate_f32_open(),
+            1.0 - INV_2POW24
+        );
+
+        // The bounded generation rejects and redraws very small outputs,
+        // so start from a state with a small output that is accepted.
+        let low_state = previous_state(1 << 74);
+        assert_eq!(Lehmer64::from_state(low_state).generate_f64_closed(), 0.0);
+        assert_eq!(Lehmer64::from_state(low_state).generate_f32_closed(), 0.0);
+        assert_eq!(Lehmer64::from_state(max_state).generate_f64_closed(), 1.0);
+        assert_eq!(Lehmer64::from_state(max_state).generate_f32_closed(), 1.0);
+    }
+
+    /// Test that ranged floats stay within their bounds.
+    #[test]
+    fn float_in_range_test() {
+        let mut rn = Lehmer64::new(0);
+        let ranges = [
+            (0.0, 1.0),
+            (-1.0, 1.0),
+            (-10.0, -9.5),
+            (-3.0, 1000.0),
+            (1.0, 1.0 + f64::EPSILON),
+            (f64::MIN, f64::MAX),
+        ];
+        for (lo, hi) in ranges {
+            for _ in 0..10_000 {
+                let val = rn.generate_f64_in_range(lo, hi);
+                assert!(
+                    val >= lo && val < hi,
+                    "Value {} not in [{}; {})",
+                    val,
+                    lo,
+                    hi
+                );
+            }
+        }
+        let ranges = [
+            (0.0, 1.0),
+            (-1.0, 1.0),
+            (-10.0, -9.5),
+            (-3.0, 1000.0),
+            (1.0, 1.0 + f32::EPSILON),
+            (f32::MIN, f32::MAX),
+        ];
+        for (lo, hi) in ranges {
+            for _ in 0..10_000 {
+                let val = rn.generate_f32_in_range(lo, hi);
+                assert!(
+                    val >= lo && val < hi,
+                    "Value {} not in [{}; {})",
+                    val,
+                    lo,
+                    hi
+                );
+            }
+        }
+        // Reversed and empty ranges
+        for _ in 0..1000 {
+            let val = rn.generate_f64_in_range(2.0, -2.0);
+            assert!(val > -2.0 && val <= 2.0, "Value {} not in (-2; 2]", val);
+        }
+        assert_eq!(rn.generate_f64_in_range(3.0, 3.0), 3.0);
+        assert_eq!(rn.generate_f32_in_range(-3.0, -3.0), -3.0);
+        assert!(rn.generate_f64_in_range(f64::NAN, 1.0).is_nan());
+    }
+
+    /// Test that nonzero generation skips zeros and otherwise matches regular generation.
+    #[test]
+    fn nonzero_test() {
+        let mut rn = Lehmer64::new(0);
+        let mut reference = Lehmer64::new(0);
+        let mut skipped_zeros = 0;
+        for _ in 0..10_000 {
+            let val: NonZeroU8 = rn.generate_nonzero_u8();
+            let mut expected = reference.generate_u8();
+            while expected == 0 {
+                skipped_zeros += 1;
+                expected = reference.generate_u8();
+            }
+            assert_eq!(val.get(), expected);
+        }
+        assert!(skipped_zeros > 0, "No zero was generated and skipped");
+
+        for _ in 0..10_000 {
+            let val: NonZeroU64 = rn.generate_nonzero_u64();
+            assert_ne!(val.get(), 0);
+        }
+    }
+
+    /// Test that generated characters are valid.
+    #[test]
+    fn char_test() {
+        let mut rn = Lehmer64::new(0);
+        for _ in 0..10_000 {
+            let val = rn.generate_char();
+            assert_eq!(char::from_u32(val as u32), Some(val));
+        }
+
+        let mut seen = [false; 128];
+        for _ in 0..10_000 {
+            let val = rn.generate_ascii_alphanumeric();
+            assert!(val.is_ascii_alphanumeric(), "Invalid character {}", val);
+            seen[val as usize] = true;
+        }
+        assert_eq!(seen.iter().filter(|&&x| x).count(), 62);
+    }
+
+    /// Test that ratio weighted booleans have the correct probability.
+    #[test]
+    fn weighted_bool_ratio_test() {
+        const SAMPLES: u32 = 70_000;
+        let mut rn = Lehmer64::new(0);
+        let hits = (0..SAMPLES)
+            .filter(|_| rn.generate_weighted_bool_ratio(3, 7))
+            .count() as u32;
+        let expected = SAMPLES / 7 * 3;
+        assert!(
+            hits.abs_diff(expected) < expected / 50,
+            "{} hits, expected about {}",
+            hits,
+            expected
+        );
+
+        for _ in 0..1000 {
+            assert!(!rn.generate_weighted_bool_ratio(0, 5));
+            assert!(rn.generate_weighted_bool_ratio(5, 5));
+            assert!(rn.generate_weighted_bool_ratio(u64::MAX, 1));
+        }
+    }
+
+    /// Test that a zero denominator panics.
+    #[test]
+    #[should_panic]
+    fn weighted_bool_ratio_zero_denominator_test() {
+        let mut rn = Lehmer64::new(0);
+        rn.generate_weighted_bool_ratio(0, 0);
+    }
+
+    /// Test that one in n chances are hit about once every n tries.
+    #[test]
+    fn bool_1_in_n_test() {
+        const SAMPLES: u32 = 1_000_000;
+        const N: u64 = 1000;
+        let mut rn = Lehmer64::new(0);
+        let hits = (0..SAMPLES).filter(|_| rn.generate_bool_1_in_n(N)).count() as u32;
+        let expected = SAMPLES / N as u32;
+        assert!(
+            hits.abs_diff(expected) < expected / 10,
+            "{} hits, expected about {}",
+            hits,
+            expected
+        );
+
+        for _ in 0..1000 {
+            assert!(rn.generate_bool_1_in_n(1));
+        }
+    }
+
+    /// Test that a zero n panics.
+    #[test]
+    #[should_panic]
+    fn bool_1_in_n_zero_test() {
+        let mut rn = Lehmer64::new(0);
+        rn.generate_bool_1_in_n(0);
+    }
+
+    /// Test that the Bernoulli sampler matches a direct comparison.
+    #[test]
+    fn bernoulli_test() {
+        for chance in [0.0, 1e-20, 0.1, 0.25, 0.5, 3.0 / 7.0, 0.999, 1.0] {
+            let sampler = Bernoulli::new(chance).unwrap();
+            let mut rn = Lehmer64::new(0);
+            let mut reference = Lehmer64::new(0);
+            for _ in 0..10_000 {
+                assert_eq!(
+                    sampler.sample(&mut rn),
+                    reference.generate_f64() < chance,
+                    "Failed with chance={}",
+                    chance
+                );
+            }
+        }
+        let always = Bernoulli::new(1.0).unwrap();
+        let never = Bernoulli::new(0.0).unwrap();
+        let mut rn = Lehmer64::new(0);
+        for _ in 0..1000 {
+            assert!(always.sample(&mut rn));
+            assert!(!never.sample(&mut rn));
+        }
+        assert!(Bernoulli::new(-0.1).is_none());
+        assert!(Bernoulli::new(1.1).is_none());
+        assert!(Bernoulli::new(f64::NAN).is_none());
+    }
+
+    /// Test that filling a slice matches the type specific generation functions.
+    #[test]
+    fn fill_test() {
+        let mut rn = Lehmer64::new(0);
+        let mut reference = Lehmer64::new(0);
+        let mut values = [0u16; 5];
+        rn.fill(&mut values);
+        for val in values {
+            assert_eq!(val, reference.generate_u16());
+        }
+
+        let mut values = [0i64; 5];
+        rn.fill(&mut values);
+        for val in values {
+            assert_eq!(val, reference.generate_i64());
+        }
+
+        let mut values = [0u128; 5];
+        rn.fill(&mut values);
+        for val in values {
+            assert_eq!(val, reference.generate_u128());
+        }
+
+        let mut values = [0i128; 5];
+        rn.fill(&mut values);
+        for val in values {
+            assert_eq!(val, reference.generate_i128());
+        }
+        assert_eq!(rn.state, reference.state);
+    }
+
+    /// Test PCG32 against the reference implementation.
+    #[test]
+    fn pcg32_reference_test() {
+        // Output of the reference pcg32-demo.
+        let mut rn = Pcg32::new(42, 54);
+        for expected in [
+            0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e,
+        ] {
+            assert_eq!(rn.generate_u32(), expected);
+        }
+
+        let mut rn = Pcg32::new(42, 1);
+        for expected in [
+            0x4df1ccf9, 0xe5838752, 0x58ed9e10, 0xf3e37b51, 0xe7664374, 0x6afde4a8,
+        ] {
+            assert_eq!(rn.generate_u32(), expected);
+        }
+    }
+
+    /// Test that PCG32 values wider than 32 bits are composed correctly.
+    #[test]
+    fn pcg32_composition_test() {
+        let mut rn = Pcg32::new(42, 54);
+        assert_eq!(rn.generate_u64(), 0xa15c02b77b47f409);
+        assert_eq!(rn.generate_u128(), 0xba1d333083d2f293bfa4784bcbed606e);
+
+        let mut rn = Pcg32::new(42, 54);
+        assert_eq!(rn.generate_u8(), 0xb7);
+        assert_eq!(rn.generate_i16(), 0xf409u16 as i16);
+        assert!(rn.generate_f64() < 1.0);
+        assert!(rn.generate_f32() < 1.0);
+
+        // Different streams produce different sequences.
+        let mut a = Pcg32::new(42, 1);
+        let mut b = Pcg32::new(42, 2);
+        assert!((0..100).any(|_| a.generate_u32() != b.generate_u32()));
+    }
+
+    /// Test that the shared PCG32 functions are derived from the reference output.
+    #[test]
+    fn pcg32_derived_test() {
+        let mut rn = Pcg32::new(42, 54);
+        assert_eq!(rn.generate_f32(), (0xa15c02b7u32 >> 8) as f32 * INV_2POW24);
+        assert!(rn.generate_bool()); // 0x7b47f409
+        assert!(!rn.generate_bool()); // 0xba1d3330
+        assert_eq!(rn.generate_i32(), 0x83d2f293u32 as i32);
+
+        let mut rn = Pcg32::new(42, 54);
+        let mut trues = 0;
+        for _ in 0..10_000 {
+            trues += rn.generate_bool() as u32;
+            let val = rn.generate_f32();
+            assert!((0.0..1.0).contains(&val), "Value {} out of range", val);
+        }
+        assert!(
+            trues.abs_diff(5000) < 250,
+            "{} of 10000 values were true",
+            trues
+        );
+    }
+
+    /// Test Xoshiro256** against the reference implementation.
+    #[test]
+    fn xoshiro256starstar_reference_test() {
+        let mut rn = Xoshiro256StarStar::new(0);
+        for expected in [
+            0x99ec5f36cb75f2b4,
+            0xbf6e1f784956452a,
+            0x1a5f849d4933e6e0,
+            0x6aa594f1262d2d2c,
+            0xbba5ad4a1f842e59,
+        ] {
+            assert_eq!(rn.generate_u64(), expected);
+        }
+
+        let mut rn = Xoshiro256StarStar::new(12345);
+        assert_eq!(rn.generate_u64(), 0xbe6a36374160d49b);
+        assert_eq!(rn.generate_u32(), 0x214aaa06);
+
+        let mut rn = Xoshiro256StarStar::new(0);
+        rn.jump();
+        for expected in [0x376215edc846d62c, 0x57c0611de8350ca7, 0xbc46a3515afee385] {
+            assert_eq!(rn.generate_u64(), expected);
+        }
+    }
+
+    /// Test that a jumped generator does not overlap with the original one.
+    #[test]
+    fn xoshiro256starstar_jump_test() {
+        const SAMPLES: usize = 1000;
+        let mut original = Xoshiro256StarStar::new(0);
+        let mut jumped = original;
+        jumped.jump();
+        let mut original_values = [0u64; SAMPLES];
+        let mut jumped_values = [0u64; SAMPLES];
+        for (a, b) in original_values.iter_mut().zip(jumped_values.iter_mut()) {
+            *a = original.generate_u64();
+            *b = jumped.generate_u64();
+        }
+        original_values.sort_unstable();
+        for val in jumped_values {
+            assert!(
+                original_values.binary_search(&val).is_err(),
+                "Value {:#x} overlaps",
+                val
+            );
+        }
+    }
+
+    /// Test SplitMix64 against the reference implementation.
+    #[test]
+    fn splitmix64_reference_test() {
+        let mut rn = SplitMix64::new(0);
+        for expected in [
+            0xe220a8397b1dcdaf,
+            0x6e789e6aa1b965f4,
+            0x06c45d188009454f,
+            0xf88bb8a8724c81ec,
+        ] {
+            assert_eq!(rn.generate_u64(), expected);
+        }
+    }
+
+    /// Test that seeding Lehmer64 through SplitMix64 decorrelates counter seeds.
+    #[test]
+    fn splitmix64_seeding_test() {
+        // The states of consecutive raw seeds are multiples of each other,
+        // so the first value of seed 2 is about twice the first value of seed 1.
+        let raw_1 = Lehmer64::new(1).generate_u64();
+        let raw_2 = Lehmer64::new(2).generate_u64();
+        assert!(raw_2.wrapping_sub(raw_1.wrapping_mul(2)) <= 1);
+
+        for seed in 1..100 {
+            let first = Lehmer64::new(SplitMix64::new(seed).generate_u128()).generate_u64();
+            let second = Lehmer64::new(SplitMix64::new(seed * 2).generate_u128()).generate_u64();
+            assert!(
+                second.wrapping_sub(first.wrapping_mul(2)) > 1,
+                "Failed with seed={}",
+                seed
+            );
+        }
+    }
+
+    /// Fill a buffer through the generic interface.
+    fn fill_random<R: RandomGenerator>(rng: &mut R, buf: &mut [u64]) {
+        for val in buf {
+            *val = rng.generate_u64();
+        }
+    }
+
+    /// Generator that counts in fixed steps, to check the default functions.
+    #[derive(Clone)]
+    struct CountingGenerator(u64);
+    impl RandomGenerator for CountingGenerator {
+        fn generate_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x0123456789abcdef);
+            self.0
+        }
+        fn generate_u32(&mut self) -> u32 {
+            (self.generate_u64() >> 32) as u32
+        }
+    }
+
+    /// Run the generic interface on a generator and compare it to a copy of the same generator.
+    fn random_generator_test<R: RandomGenerator + Clone>(rng: &mut R) {
+        let mut reference = rng.clone();
+        let mut buf = [0u64; 8];
+        fill_random(rng, &mut buf);
+        for val in buf {
+            assert_eq!(val, reference.generate_u64());
+        }
+        assert_eq!(rng.generate_u32(), reference.generate_u32());
+        assert_eq!(rng.generate_u16(), reference.generate_u16());
+        assert_eq!(rng.generate_u8(), reference.generate_u8());
+        assert_eq!(rng.generate_usize(), reference.generate_usize());
+        assert_eq!(rng.generate_i64(), reference.generate_i64());
+        assert_eq!(rng.generate_bool(), reference.generate_bool());
+        for _ in 0..1000 {
+            let val = rng.generate_f64();
+            assert!((0.0..1.0).contains(&val), "Value {} out of range", val);
+            let val = rng.generate_f32();
+            assert!((0.0..1.0).contains(&val), "Value {} out of range", val);
+            assert!(!rng.generate_weighted_bool(0.0));
+            assert!(rng.generate_weighted_bool(1.0));
+        }
+    }
+
+    /// Test the generic interface of all generators.
+    #[test]
+    fn random_generator_trait_test() {
+        random_generator_test(&mut Lehmer64::new(0));
+        random_generator_test(&mut Pcg32::new(42, 54));
+        random_generator_test(&mut Xoshiro256StarStar::new(0));
+        random_generator_test(&mut SplitMix64::new(0));
+
+        // The trait functions are identical to the inherent functions.
+        let mut rn = Lehmer64::new(0);
+        let mut reference = Lehmer64::new(0);
+        assert_eq!(
+            RandomGenerator::generate_f64(&mut rn),
+            reference.generate_f64()
+        );
+        assert_eq!(
+            RandomGenerator::generate_f32(&mut rn),
+            reference.generate_f32()
+        );
+        assert_eq!(
+            RandomGenerator::generate_bool(&mut rn),
+            reference.generate_bool()
+        );
+
+        // The default functions are derived from generate_u64.
+        const STEP: u64 = 0x0123456789abcdef;
+        random_generator_test(&mut CountingGenerator(0));
+        let mut rn = CountingGenerator(0);
+        assert_eq!(rn.generate_u16(), STEP as u16);
+        assert_eq!(rn.generate_u8(), STEP.wrapping_mul(2) as u8);
+        assert_eq!(rn.generate_i64(), STEP.wrapping_mul(3) as i64);
+        assert_eq!(
+            rn.generate_f64(),
+            (STEP.wrapping_mul(4) >> 11) as f64 * INV_2POW53
+        );
+        assert_eq!(rn.generate_bool(), STEP.wrapping_mul(5) & 1 != 0);
     }
 }
@@ -18,10 +18,16 @@
 //! assert_eq!(sign::int_sign_i64(0), 1);
 //! assert_eq!(sign::int_sign_i128(i128::MAX), 1);
 //!
+//! // Unlike int_sign, int_signum treats zero as zero.
+//! assert_eq!(sign::int_signum_i64(0), 0);
+//! assert_eq!(sign::int_signum_i32(i32::MIN), -1);
+//!
 //! assert!(sign::int_opposite_sign_i8(0, -1));
 //! assert!(sign::int_same_sign_isize(0, 1));
 //! ```
 
+use crate::traits::{BaseInt, SignedInt};
+
 /// Define a function that returns the sign of a signed integer.
 macro_rules! generic_sign_function {
     ($fnname:ident, $datatype:ty) => {
@@ -44,6 +50,243 @@ generic_sign_function!(int_sign_i64, i64);
 generic_sign_function!(int_sign_i128, i128);
 generic_sign_function!(int_sign_isize, isize);
 
+/// Returns the sign of a signed integer.
+/// 1 if x > -1, -1 otherwise.
+/// Behaviour similar to .signum() except zero is treated as positive.
+/// Bound to `SignedInt`, since unsigned values with the MSB set would overflow.
+#[inline]
+pub fn int_sign<T: SignedInt>(x: T) -> T {
+    T::ONE - (x & T::MSB).rotate_right(T::BITS_M_2)
+}
+
+/// Define functions that return and convert the sign mask of a signed integer.
+macro_rules! generic_sign_mask {
+    ($fnname:ident, $fnname_multiplier:ident, $datatype:ty) => {
+        /// Returns all ones (-1) if x is negative, all zeros (0) otherwise.
+        /// Not the same as `int_sign`, which returns 1 for non-negative x.
+        #[inline]
+        pub const fn $fnname(x: $datatype) -> $datatype {
+            const BITS_M_1: u32 = <$datatype>::BITS - 1;
+            x >> BITS_M_1
+        }
+
+        /// Converts a sign mask (0 or -1) to a multiplier (1 or -1).
+        #[inline]
+        pub const fn $fnname_multiplier(mask: $datatype) -> $datatype {
+            mask | 1
+        }
+    };
+}
+
+generic_sign_mask!(sign_mask_i8, sign_mask_to_multiplier_i8, i8);
+generic_sign_mask!(sign_mask_i16, sign_mask_to_multiplier_i16, i16);
+generic_sign_mask!(sign_mask_i32, sign_mask_to_multiplier_i32, i32);
+generic_sign_mask!(sign_mask_i64, sign_mask_to_multiplier_i64, i64);
+generic_sign_mask!(sign_mask_i128, sign_mask_to_multiplier_i128, i128);
+generic_sign_mask!(sign_mask_isize, sign_mask_to_multiplier_isize, isize);
+
+/// Define a function that returns the three-way sign of a signed integer.
+macro_rules! generic_signum_function {
+    ($fnname:ident, $datatype:ty, $unsigned:ty) => {
+        /// Returns the sign of a signed integer.
+        /// 1 if x > 0, 0 if x == 0, -1 if x < 0.
+        /// Behaviour identical to .signum(), unlike `int_sign` zero is treated as zero.
+        #[inline]
+        pub fn $fnname(x: $datatype) -> $datatype {
+            const BITS_M_1: u32 = <$datatype>::BITS - 1;
+            // All ones for negative x, zero otherwise.
+            let negative_mask = x >> BITS_M_1;
+            // One for positive x, zero otherwise.
+            // MIN is its own wrapping negation, but is already covered by the negative mask.
+            let positive_bit = ((x.wrapping_neg() as $unsigned) >> BITS_M_1) as $datatype;
+            negative_mask | positive_bit
+        }
+    };
+}
+
+generic_signum_function!(int_signum_i8, i8, u8);
+generic_signum_function!(int_signum_i16, i16, u16);
+generic_signum_function!(int_signum_i32, i32, u32);
+generic_signum_function!(int_signum_i64, i64, u64);
+generic_signum_function!(int_signum_i128, i128, u128);
+generic_signum_function!(int_signum_isize, isize, usize);
+
+/// Define functions that return the absolute value of a signed integer.
+macro_rules! generic_abs_function {
+    ($fnname:ident, $fnname_saturating:ident, $fnname_checked:ident, $datatype:ty) => {
+        /// Returns the absolute value of a signed integer.
+        /// Wraps for MIN, which has no positive counterpart: abs(MIN) == MIN.
+        /// Behaviour identical to .wrapping_abs().
+        #[inline]
+        pub fn $fnname(x: $datatype) -> $datatype {
+            const BITS_M_1: u32 = <$datatype>::BITS - 1;
+            let mask = x >> BITS_M_1;
+            x.wrapping_add(mask) ^ mask
+        }
+
+        /// Returns the absolute value of a signed integer.
+        /// Saturates for MIN, which has no positive counterpart: abs(MIN) == MAX.
+        /// Behaviour identical to .saturating_abs().
+        #[inline]
+        pub fn $fnname_saturating(x: $datatype) -> $datatype {
+            const BITS_M_1: u32 = <$datatype>::BITS - 1;
+            let wrapped = $fnname(x);
+            // Only MIN is still negative, flipping all its bits gives MAX.
+            wrapped ^ (wrapped >> BITS_M_1)
+        }
+
+        /// Returns the absolute value of a signed integer.
+        /// Returns None for MIN, which has no positive counterpart.
+        /// Behaviour identical to .checked_abs().
+        #[inline]
+        pub fn $fnname_checked(x: $datatype) -> Option<$datatype> {
+            let wrapped = $fnname(x);
+            if wrapped < 0 {
+                None
+            } else {
+                Some(wrapped)
+            }
+        }
+    };
+}
+
+generic_abs_function!(abs_i8, abs_saturating_i8, abs_checked_i8, i8);
+generic_abs_function!(abs_i16, abs_saturating_i16, abs_checked_i16, i16);
+generic_abs_function!(abs_i32, abs_saturating_i32, abs_checked_i32, i32);
+generic_abs_function!(abs_i64, abs_saturating_i64, abs_checked_i64, i64);
+generic_abs_function!(abs_i128, abs_saturating_i128, abs_checked_i128, i128);
+generic_abs_function!(abs_isize, abs_saturating_isize, abs_checked_isize, isize);
+
+/// Define a function that combines the magnitude of one signed integer with the sign of another.
+macro_rules! generic_copy_sign_function {
+    ($fnname:ident, $absfn:ident, $datatype:ty) => {
+        /// Returns a value with the absolute value of `magnitude` and the sign of `sign_source`.
+        /// Zero is considered positive.
+        /// Wraps for a magnitude of MIN, which has no positive counterpart:
+        /// copy_sign(MIN, 1) == MIN.
+        #[inline]
+        pub fn $fnname(magnitude: $datatype, sign_source: $datatype) -> $datatype {
+            const BITS_M_1: u32 = <$datatype>::BITS - 1;
+            let mask = sign_source >> BITS_M_1;
+            // Negates the absolute value if the mask is all ones.
+            ($absfn(magnitude) ^ mask).wrapping_sub(mask)
+        }
+    };
+}
+
+generic_copy_sign_function!(copy_sign_i8, abs_i8, i8);
+generic_copy_sign_function!(copy_sign_i16, abs_i16, i16);
+generic_copy_sign_function!(copy_sign_i32, abs_i32, i32);
+generic_copy_sign_function!(copy_sign_i64, abs_i64, i64);
+generic_copy_sign_function!(copy_sign_i128, abs_i128, i128);
+generic_copy_sign_function!(copy_sign_isize, abs_isize, isize);
+
+/// Define a function that negates a signed integer if a condition is true.
+macro_rules! generic_negate_if {
+    ($fnname:ident, $datatype:ty) => {
+        /// Returns -x if condition is true, x otherwise, without branching.
+        /// Wraps for MIN, which has no positive counterpart: negate_if(MIN, true) == MIN.
+        #[inline]
+        pub fn $fnname(x: $datatype, condition: bool) -> $datatype {
+            let condition = condition as $datatype;
+            // Two's complement negation, only applied if the mask is all ones.
+            (x ^ condition.wrapping_neg()).wrapping_add(condition)
+        }
+    };
+}
+
+generic_negate_if!(negate_if_i8, i8);
+generic_negate_if!(negate_if_i16, i16);
+generic_negate_if!(negate_if_i32, i32);
+generic_negate_if!(negate_if_i64, i64);
+generic_negate_if!(negate_if_i128, i128);
+generic_negate_if!(negate_if_isize, isize);
+
+/// Define a function that returns the absolute difference of two signed integers.
+macro_rules! generic_abs_diff_function {
+    ($fnname:ident, $datatype:ty, $unsigned:ty) => {
+        /// Returns the absolute difference |x - y| of two signed integers
+        /// as the unsigned type of the same width, which can not overflow.
+        /// Behaviour identical to .abs_diff().
+        #[inline]
+        pub fn $fnname(x: $datatype, y: $datatype) -> $unsigned {
+            // The difference always fits in the unsigned type,
+            // so the wrapping subtraction of the bit patterns is exact.
+            if x > y {
+                (x as $unsigned).wrapping_sub(y as $unsigned)
+            } else {
+                (y as $unsigned).wrapping_sub(x as $unsigned)
+            }
+        }
+    };
+}
+
+generic_abs_diff_function!(abs_diff_i8, i8, u8);
+generic_abs_diff_function!(abs_diff_i16, i16, u16);
+generic_abs_diff_function!(abs_diff_i32, i32, u32);
+generic_abs_diff_function!(abs_diff_i64, i64, u64);
+generic_abs_diff_function!(abs_diff_i128, i128, u128);
+generic_abs_diff_function!(abs_diff_isize, isize, usize);
+
+/// Define a function that selects one of two integers without branching.
+macro_rules! generic_select {
+    ($fnname:ident, $datatype:ty) => {
+        /// Returns a if condition is true, b otherwise.
+        /// The result is computed with a mask instead of a data dependent branch.
+        #[inline]
+        pub fn $fnname(condition: bool, a: $datatype, b: $datatype) -> $datatype {
+            // All ones if a should be selected, zero otherwise.
+            let mask = (condition as $datatype).wrapping_neg();
+            b ^ ((a ^ b) & mask)
+        }
+    };
+}
+
+generic_select!(select_u8, u8);
+generic_select!(select_u16, u16);
+generic_select!(select_u32, u32);
+generic_select!(select_u64, u64);
+generic_select!(select_u128, u128);
+generic_select!(select_usize, usize);
+generic_select!(select_i8, i8);
+generic_select!(select_i16, i16);
+generic_select!(select_i32, i32);
+generic_select!(select_i64, i64);
+generic_select!(select_i128, i128);
+generic_select!(select_isize, isize);
+
+/// Define functions that return the minimum and maximum of two integers without branching.
+macro_rules! generic_minmax {
+    ($fnname_min:ident, $fnname_max:ident, $fnname_select:ident, $datatype:ty) => {
+        /// Returns the smaller of x and y.
+        /// Valid for all inputs, no intermediate value can overflow.
+        #[inline]
+        pub fn $fnname_min(x: $datatype, y: $datatype) -> $datatype {
+            $fnname_select(x < y, x, y)
+        }
+
+        /// Returns the larger of x and y.
+        /// Valid for all inputs, no intermediate value can overflow.
+        #[inline]
+        pub fn $fnname_max(x: $datatype, y: $datatype) -> $datatype {
+            $fnname_select(x > y, x, y)
+        }
+    };
+}
+
+generic_minmax!(min_u8, max_u8, select_u8, u8);
+generic_minmax!(min_u16, max_u16, select_u16, u16);
+generic_minmax!(min_u32, max_u32, select_u32, u32);
+generic_minmax!(min_u64, max_u64, select_u64, u64);
+generic_minmax!(min_u128, max_u128, select_u128, u128);
+generic_minmax!(min_usize, max_usize, select_usize, usize);
+generic_minmax!(min_i8, max_i8, select_i8, i8);
+generic_minmax!(min_i16, max_i16, select_i16, i16);
+generic_minmax!(min_i32, max_i32, select_i32, i32);
+generic_minmax!(min_i64, max_i64, select_i64, i64);
+generic_minmax!(min_i128, max_i128, select_i128, i128);
+generic_minmax!(min_isize, max_isize, select_isize, isize);
+
 /// Define a function that returns true if both supplied ints have opposite signs.
 macro_rules! generic_sign_comparison_functions {
     ($fnname_opposite:ident, $fnname_same:ident, $datatype:ty) => {
@@ -70,6 +313,81 @@ generic_sign_comparison_functions!(int_opposite_sign_i64, int_same_sign_i64, i64
 generic_sign_comparison_functions!(int_opposite_sign_i128, int_same_sign_i128, i128);
 generic_sign_comparison_functions!(int_opposite_sign_isize, int_same_sign_isize, isize);
 
+/// Returns true when x and y have opposite signs.
+/// Zero is considered positive, unsigned values are always positive.
+#[inline]
+pub fn int_opposite_sign<T: BaseInt>(x: T, y: T) -> bool {
+    (x ^ y) < T::ZERO
+}
+
+/// Returns true when x and y have the same sign.
+/// Zero is considered positive, unsigned values are always positive.
+#[inline]
+pub fn int_same_sign<T: BaseInt>(x: T, y: T) -> bool {
+    !int_opposite_sign(x, y)
+}
+
+/// Define functions that detect sign changes between adjacent elements of a slice.
+macro_rules! generic_count_sign_changes {
+    ($fnname_count:ident, $fnname_has:ident, $fnname_opposite:ident, $datatype:ty) => {
+        /// Returns the number of adjacent pairs in the slice that have opposite signs.
+        /// Zero is considered positive.
+        /// Returns 0 for slices with less than two elements.
+        pub fn $fnname_count(slice: &[$datatype]) -> usize {
+            slice
+                .windows(2)
+                .filter(|pair| $fnname_opposite(pair[0], pair[1]))
+                .count()
+        }
+
+        /// Returns true if any adjacent pair in the slice has opposite signs.
+        /// Zero is considered positive.
+        /// Stops at the first sign change.
+        pub fn $fnname_has(slice: &[$datatype]) -> bool {
+            slice
+                .windows(2)
+                .any(|pair| $fnname_opposite(pair[0], pair[1]))
+        }
+    };
+}
+
+generic_count_sign_changes!(
+    count_sign_changes_i8,
+    has_sign_change_i8,
+    int_opposite_sign_i8,
+    i8
+);
+generic_count_sign_changes!(
+    count_sign_changes_i16,
+    has_sign_change_i16,
+    int_opposite_sign_i16,
+    i16
+);
+generic_count_sign_changes!(
+    count_sign_changes_i32,
+    has_sign_change_i32,
+    int_opposite_sign_i32,
+    i32
+);
+generic_count_sign_changes!(
+    count_sign_changes_i64,
+    has_sign_change_i64,
+    int_opposite_sign_i64,
+    i64
+);
+generic_count_sign_changes!(
+    count_sign_changes_i128,
+    has_sign_change_i128,
+    int_opposite_sign_i128,
+    i128
+);
+generic_count_sign_changes!(
+    count_sign_changes_isize,
+    has_sign_change_isize,
+    int_opposite_sign_isize,
+    isize
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,10 +412,541 @@ mod tests {
     test_int_sign!(int_sign_i64, i64, test_i64_int_sign);
     test_int_sign!(int_sign_i128, i128, test_i128_int_sign);
     test_int_sign!(int_sign_isize, isize, test_isize_int_sign);
+    test_int_sign!(int_sign::<i8>, i8, test_i8_generic_int_sign);
+    test_int_sign!(int_sign::<i16>, i16, test_i16_generic_int_sign);
+    test_int_sign!(int_sign::<i32>, i32, test_i32_generic_int_sign);
+    test_int_sign!(int_sign::<i64>, i64, test_i64_generic_int_sign);
+    test_int_sign!(int_sign::<i128>, i128, test_i128_generic_int_sign);
+    test_int_sign!(int_sign::<isize>, isize, test_isize_generic_int_sign);
+
+    /// Defines a test function for sign mask functions.
+    macro_rules! test_sign_mask {
+        ($testfn:expr, $testfn_multiplier:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                assert_eq!($testfn(<$datatype>::MIN), -1, "Failed with x=MININT");
+                assert_eq!($testfn(-1), -1, "Failed with x=-1");
+                assert_eq!($testfn(0), 0, "Failed with x=0");
+                assert_eq!($testfn(1), 0, "Failed with x=1");
+                assert_eq!($testfn(<$datatype>::MAX), 0, "Failed with x=MAXINT");
+
+                assert_eq!($testfn_multiplier(0), 1, "Failed multiplier with mask=0");
+                assert_eq!($testfn_multiplier(-1), -1, "Failed multiplier with mask=-1");
+                for x in [<$datatype>::MIN, -1, 0, 1, <$datatype>::MAX] {
+                    assert_eq!(
+                        $testfn_multiplier($testfn(x)),
+                        if x < 0 { -1 } else { 1 },
+                        "Failed multiplier with x={}",
+                        x
+                    );
+                }
+
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..10_000 {
+                    let x: $datatype = rn.generate();
+                    assert_eq!(
+                        $testfn(x),
+                        if x < 0 { -1 } else { 0 },
+                        "Failed with x={}",
+                        x
+                    );
+                }
+            }
+        };
+    }
+
+    // Compile time tests to ensure the sign mask functions remain const.
+    const _: () = assert!(sign_mask_i32(i32::MIN) == -1);
+    const _: () = assert!(sign_mask_to_multiplier_i64(sign_mask_i64(0)) == 1);
+
+    test_sign_mask!(
+        sign_mask_i8,
+        sign_mask_to_multiplier_i8,
+        i8,
+        test_i8_sign_mask
+    );
+    test_sign_mask!(
+        sign_mask_i16,
+        sign_mask_to_multiplier_i16,
+        i16,
+        test_i16_sign_mask
+    );
+    test_sign_mask!(
+        sign_mask_i32,
+        sign_mask_to_multiplier_i32,
+        i32,
+        test_i32_sign_mask
+    );
+    test_sign_mask!(
+        sign_mask_i64,
+        sign_mask_to_multiplier_i64,
+        i64,
+        test_i64_sign_mask
+    );
+    test_sign_mask!(
+        sign_mask_i128,
+        sign_mask_to_multiplier_i128,
+        i128,
+        test_i128_sign_mask
+    );
+    test_sign_mask!(
+        sign_mask_isize,
+        sign_mask_to_multiplier_isize,
+        isize,
+        test_isize_sign_mask
+    );
+
+    /// Defines a test function for integer signum function.
+    macro_rules! test_int_signum {
+        ($testfn:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                assert_eq!($testfn(<$datatype>::MIN), -1, "Failed with x=MININT");
+                assert_eq!($testfn(-1), -1, "Failed with x=-1");
+                assert_eq!($testfn(0), 0, "Failed with x=0");
+                assert_eq!($testfn(1), 1, "Failed with x=1");
+                assert_eq!($testfn(<$datatype>::MAX), 1, "Failed with x=MAXINT");
+                for x in [<$datatype>::MIN + 1, -2, 2, <$datatype>::MAX - 1] {
+                    assert_eq!($testfn(x), x.signum(), "Failed with x={}", x);
+                }
+            }
+        };
+    }
+
+    test_int_signum!(int_signum_i8, i8, test_i8_int_signum);
+    test_int_signum!(int_signum_i16, i16, test_i16_int_signum);
+    test_int_signum!(int_signum_i32, i32, test_i32_int_signum);
+    test_int_signum!(int_signum_i64, i64, test_i64_int_signum);
+    test_int_signum!(int_signum_i128, i128, test_i128_int_signum);
+    test_int_signum!(int_signum_isize, isize, test_isize_int_signum);
+
+    /// Defines a test function for absolute value functions.
+    macro_rules! test_abs {
+        ($testfn:expr, $testfn_saturating:expr, $testfn_checked:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                assert_eq!(
+                    $testfn(<$datatype>::MIN),
+                    <$datatype>::MIN,
+                    "Failed with x=MININT"
+                );
+                assert_eq!($testfn(-1), 1, "Failed with x=-1");
+                assert_eq!($testfn(0), 0, "Failed with x=0");
+                assert_eq!($testfn(1), 1, "Failed with x=1");
+                assert_eq!(
+                    $testfn(<$datatype>::MAX),
+                    <$datatype>::MAX,
+                    "Failed with x=MAXINT"
+                );
+
+                assert_eq!(
+                    $testfn_saturating(<$datatype>::MIN),
+                    <$datatype>::MAX,
+                    "Failed saturating with x=MININT"
+                );
+                assert_eq!(
+                    $testfn_saturating(<$datatype>::MIN + 1),
+                    <$datatype>::MAX,
+                    "Failed saturating with x=MININT + 1"
+                );
+                assert_eq!($testfn_saturating(-1), 1, "Failed saturating with x=-1");
+                assert_eq!($testfn_saturating(0), 0, "Failed saturating with x=0");
+                assert_eq!($testfn_saturating(1), 1, "Failed saturating with x=1");
+                assert_eq!(
+                    $testfn_saturating(<$datatype>::MAX),
+                    <$datatype>::MAX,
+                    "Failed saturating with x=MAXINT"
+                );
+
+                assert_eq!(
+                    $testfn_checked(<$datatype>::MIN),
+                    None,
+                    "Failed checked with x=MININT"
+                );
+                assert_eq!(
+                    $testfn_checked(<$datatype>::MIN + 1),
+                    Some(<$datatype>::MAX),
+                    "Failed checked with x=MININT + 1"
+                );
+                assert_eq!($testfn_checked(-1), Some(1), "Failed checked with x=-1");
+                assert_eq!($testfn_checked(0), Some(0), "Failed checked with x=0");
+                assert_eq!($testfn_checked(1), Some(1), "Failed checked with x=1");
+                assert_eq!(
+                    $testfn_checked(<$datatype>::MAX),
+                    Some(<$datatype>::MAX),
+                    "Failed checked with x=MAXINT"
+                );
+            }
+        };
+    }
+
+    test_abs!(abs_i8, abs_saturating_i8, abs_checked_i8, i8, test_i8_abs);
+    test_abs!(
+        abs_i16,
+        abs_saturating_i16,
+        abs_checked_i16,
+        i16,
+        test_i16_abs
+    );
+    test_abs!(
+        abs_i32,
+        abs_saturating_i32,
+        abs_checked_i32,
+        i32,
+        test_i32_abs
+    );
+    test_abs!(
+        abs_i64,
+        abs_saturating_i64,
+        abs_checked_i64,
+        i64,
+        test_i64_abs
+    );
+    test_abs!(
+        abs_i128,
+        abs_saturating_i128,
+        abs_checked_i128,
+        i128,
+        test_i128_abs
+    );
+    test_abs!(
+        abs_isize,
+        abs_saturating_isize,
+        abs_checked_isize,
+        isize,
+        test_isize_abs
+    );
+
+    /// Defines a test function for copy sign functions.
+    macro_rules! test_copy_sign {
+        ($testfn:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                assert_eq!($testfn(-5, 3), 5, "Failed with x=-5, y=3");
+                assert_eq!($testfn(5, -3), -5, "Failed with x=5, y=-3");
+                assert_eq!($testfn(-5, -3), -5, "Failed with x=-5, y=-3");
+                assert_eq!($testfn(5, 0), 5, "Failed with x=5, y=0");
+                assert_eq!($testfn(-5, 0), 5, "Failed with x=-5, y=0");
+                assert_eq!($testfn(0, -1), 0, "Failed with x=0, y=-1");
+                assert_eq!(
+                    $testfn(<$datatype>::MAX, <$datatype>::MIN),
+                    -<$datatype>::MAX,
+                    "Failed with x=MAXINT, y=MININT"
+                );
+                assert_eq!(
+                    $testfn(<$datatype>::MIN, 1),
+                    <$datatype>::MIN,
+                    "Failed with x=MININT, y=1"
+                );
+                assert_eq!(
+                    $testfn(<$datatype>::MIN, -1),
+                    <$datatype>::MIN,
+                    "Failed with x=MININT, y=-1"
+                );
+
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..10_000 {
+                    let x: $datatype = rn.generate();
+                    let y: $datatype = rn.generate();
+                    let expected = if y < 0 {
+                        x.wrapping_abs().wrapping_neg()
+                    } else {
+                        x.wrapping_abs()
+                    };
+                    assert_eq!($testfn(x, y), expected, "Failed with x={}, y={}", x, y);
+                }
+            }
+        };
+    }
+
+    test_copy_sign!(copy_sign_i8, i8, test_i8_copy_sign);
+    test_copy_sign!(copy_sign_i16, i16, test_i16_copy_sign);
+    test_copy_sign!(copy_sign_i32, i32, test_i32_copy_sign);
+    test_copy_sign!(copy_sign_i64, i64, test_i64_copy_sign);
+    test_copy_sign!(copy_sign_i128, i128, test_i128_copy_sign);
+    test_copy_sign!(copy_sign_isize, isize, test_isize_copy_sign);
+
+    /// Defines a test function for conditional negation functions.
+    macro_rules! test_negate_if {
+        ($testfn:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                for x in [<$datatype>::MIN + 1, -1, 0, 1, 42, <$datatype>::MAX] {
+                    assert_eq!($testfn(x, true), -x, "Failed with x={}, condition=true", x);
+                    assert_eq!($testfn(x, false), x, "Failed with x={}, condition=false", x);
+                }
+                assert_eq!(
+                    $testfn(<$datatype>::MIN, true),
+                    <$datatype>::MIN,
+                    "Failed with x=MININT, condition=true"
+                );
+                assert_eq!(
+                    $testfn(<$datatype>::MIN, false),
+                    <$datatype>::MIN,
+                    "Failed with x=MININT, condition=false"
+                );
+            }
+        };
+    }
+
+    test_negate_if!(negate_if_i8, i8, test_i8_negate_if);
+    test_negate_if!(negate_if_i16, i16, test_i16_negate_if);
+    test_negate_if!(negate_if_i32, i32, test_i32_negate_if);
+    test_negate_if!(negate_if_i64, i64, test_i64_negate_if);
+    test_negate_if!(negate_if_i128, i128, test_i128_negate_if);
+    test_negate_if!(negate_if_isize, isize, test_isize_negate_if);
+
+    /// Defines a test function for absolute difference functions.
+    macro_rules! test_abs_diff {
+        ($testfn:expr, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                let pairs: [($datatype, $datatype); 8] = [
+                    (0, 0),
+                    (0, -1),
+                    (1, -1),
+                    (5, 3),
+                    (-5, 3),
+                    (<$datatype>::MIN, 0),
+                    (<$datatype>::MAX, -1),
+                    (<$datatype>::MIN, <$datatype>::MAX),
+                ];
+                for (x, y) in pairs {
+                    assert_eq!($testfn(x, y), x.abs_diff(y), "Failed with x={}, y={}", x, y);
+                    assert_eq!(
+                        $testfn(x, y),
+                        $testfn(y, x),
+                        "Failed symmetry with x={}, y={}",
+                        x,
+                        y
+                    );
+                }
+                assert_eq!($testfn(0, -1), 1, "Failed with x=0, y=-1");
+                assert_eq!(
+                    $testfn(<$datatype>::MIN, <$datatype>::MAX),
+                    <$datatype>::MAX.abs_diff(0) * 2 + 1,
+                    "Failed with x=MININT, y=MAXINT"
+                );
+
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..10_000 {
+                    let x: $datatype = rn.generate();
+                    let y: $datatype = rn.generate();
+                    assert_eq!($testfn(x, y), x.abs_diff(y), "Failed with x={}, y={}", x, y);
+                }
+            }
+        };
+    }
+
+    test_abs_diff!(abs_diff_i8, i8, test_i8_abs_diff);
+    test_abs_diff!(abs_diff_i16, i16, test_i16_abs_diff);
+    test_abs_diff!(abs_diff_i32, i32, test_i32_abs_diff);
+    test_abs_diff!(abs_diff_i64, i64, test_i64_abs_diff);
+    test_abs_diff!(abs_diff_i128, i128, test_i128_abs_diff);
+    test_abs_diff!(abs_diff_isize, isize, test_isize_abs_diff);
+
+    /// Defines a test function for sign change detection.
+    macro_rules! test_sign_changes {
+        ($fnname_count:ident, $fnname_has:ident, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                let alternating: [$datatype; 6] =
+                    [1, -1, 2, -2, <$datatype>::MAX, <$datatype>::MIN];
+                assert_eq!($fnname_count(&alternating), 5, "Failed alternating count");
+                assert!($fnname_has(&alternating), "Failed alternating has");
+
+                let positive: [$datatype; 4] = [0, 1, <$datatype>::MAX, 0];
+                assert_eq!($fnname_count(&positive), 0, "Failed positive count");
+                assert!(!$fnname_has(&positive), "Failed positive has");
+
+                let negative: [$datatype; 3] = [-1, <$datatype>::MIN, -5];
+                assert_eq!($fnname_count(&negative), 0, "Failed negative count");
+                assert!(!$fnname_has(&negative), "Failed negative has");
+
+                let mixed: [$datatype; 6] = [3, 0, -1, -7, 0, 2];
+                assert_eq!($fnname_count(&mixed), 2, "Failed mixed count");
+                assert!($fnname_has(&mixed), "Failed mixed has");
+
+                assert_eq!($fnname_count(&[-1]), 0, "Failed single element count");
+                assert!(!$fnname_has(&[-1]), "Failed single element has");
+                assert_eq!($fnname_count(&[]), 0, "Failed empty count");
+                assert!(!$fnname_has(&[]), "Failed empty has");
+            }
+        };
+    }
+
+    test_sign_changes!(
+        count_sign_changes_i8,
+        has_sign_change_i8,
+        i8,
+        test_i8_sign_changes
+    );
+    test_sign_changes!(
+        count_sign_changes_i16,
+        has_sign_change_i16,
+        i16,
+        test_i16_sign_changes
+    );
+    test_sign_changes!(
+        count_sign_changes_i32,
+        has_sign_change_i32,
+        i32,
+        test_i32_sign_changes
+    );
+    test_sign_changes!(
+        count_sign_changes_i64,
+        has_sign_change_i64,
+        i64,
+        test_i64_sign_changes
+    );
+    test_sign_changes!(
+        count_sign_changes_i128,
+        has_sign_change_i128,
+        i128,
+        test_i128_sign_changes
+    );
+    test_sign_changes!(
+        count_sign_changes_isize,
+        has_sign_change_isize,
+        isize,
+        test_isize_sign_changes
+    );
+
+    /// Defines a test function for select functions.
+    macro_rules! test_select {
+        ($testfn:ident, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                let values: [$datatype; 7] = [
+                    <$datatype>::MIN,
+                    <$datatype>::MIN + 1,
+                    (0 as $datatype).wrapping_sub(1),
+                    0,
+                    1,
+                    <$datatype>::MAX - 1,
+                    <$datatype>::MAX,
+                ];
+                for a in values {
+                    for b in values {
+                        assert_eq!(
+                            $testfn(true, a, b),
+                            a,
+                            "Failed with cond=true, a={}, b={}",
+                            a,
+                            b
+                        );
+                        assert_eq!(
+                            $testfn(false, a, b),
+                            b,
+                            "Failed with cond=false, a={}, b={}",
+                            a,
+                            b
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    test_select!(select_u8, u8, test_u8_select);
+    test_select!(select_u16, u16, test_u16_select);
+    test_select!(select_u32, u32, test_u32_select);
+    test_select!(select_u64, u64, test_u64_select);
+    test_select!(select_u128, u128, test_u128_select);
+    test_select!(select_usize, usize, test_usize_select);
+    test_select!(select_i8, i8, test_i8_select);
+    test_select!(select_i16, i16, test_i16_select);
+    test_select!(select_i32, i32, test_i32_select);
+    test_select!(select_i64, i64, test_i64_select);
+    test_select!(select_i128, i128, test_i128_select);
+    test_select!(select_isize, isize, test_isize_select);
+
+    /// Defines a test function for min and max functions.
+    macro_rules! test_minmax {
+        ($fnname_min:ident, $fnname_max:ident, $datatype:ty, $testname:ident) => {
+            #[test]
+            fn $testname() {
+                let values: [$datatype; 7] = [
+                    <$datatype>::MIN,
+                    <$datatype>::MIN + 1,
+                    (0 as $datatype).wrapping_sub(1),
+                    0,
+                    1,
+                    <$datatype>::MAX - 1,
+                    <$datatype>::MAX,
+                ];
+                for x in values {
+                    for y in values {
+                        assert_eq!(
+                            $fnname_min(x, y),
+                            x.min(y),
+                            "Failed min with x={}, y={}",
+                            x,
+                            y
+                        );
+                        assert_eq!(
+                            $fnname_max(x, y),
+                            x.max(y),
+                            "Failed max with x={}, y={}",
+                            x,
+                            y
+                        );
+                        assert_eq!(
+                            $fnname_min(x, y),
+                            $fnname_min(y, x),
+                            "Failed min symmetry with x={}, y={}",
+                            x,
+                            y
+                        );
+                        assert_eq!(
+                            $fnname_max(x, y),
+                            $fnname_max(y, x),
+                            "Failed max symmetry with x={}, y={}",
+                            x,
+                            y
+                        );
+                    }
+                }
+
+                let mut rn = crate::rng::Lehmer64::new(0);
+                for _ in 0..10_000 {
+                    let x: $datatype = rn.generate();
+                    let y: $datatype = rn.generate();
+                    assert_eq!(
+                        $fnname_min(x, y),
+                        x.min(y),
+                        "Failed min with x={}, y={}",
+                        x,
+                        y
+                    );
+                    assert_eq!(
+                        $fnname_max(x, y),
+                        x.max(y),
+                        "Failed max with x={}, y={}",
+                        x,
+                        y
+                    );
+                }
+            }
+        };
+    }
+
+    test_minmax!(min_u8, max_u8, u8, test_u8_minmax);
+    test_minmax!(min_u16, max_u16, u16, test_u16_minmax);
+    test_minmax!(min_u32, max_u32, u32, test_u32_minmax);
+    test_minmax!(min_u64, max_u64, u64, test_u64_minmax);
+    test_minmax!(min_u128, max_u128, u128, test_u128_minmax);
+    test_minmax!(min_usize, max_usize, usize, test_usize_minmax);
+    test_minmax!(min_i8, max_i8, i8, test_i8_minmax);
+    test_minmax!(min_i16, max_i16, i16, test_i16_minmax);
+    test_minmax!(min_i32, max_i32, i32, test_i32_minmax);
+    test_minmax!(min_i64, max_i64, i64, test_i64_minmax);
+    test_minmax!(min_i128, max_i128, i128, test_i128_minmax);
+    test_minmax!(min_isize, max_isize, isize, test_isize_minmax);
 
     /// Defines a test function for integer sign comparisons.
     macro_rules! test_sign_comparison {
-        ($fnname_same:ident, $fnname_opposite:ident, $datatype:ty, $testname:ident) => {
+        ($fnname_same:expr, $fnname_opposite:expr, $datatype:ty, $testname:ident) => {
             #[test]
             fn $testname() {
                 assert!(
@@ -196,4 +1045,23 @@ mod tests {
         isize,
         test_isize_sign_comparison
     );
+    test_sign_comparison!(
+        int_same_sign::<i16>,
+        int_opposite_sign::<i16>,
+        i16,
+        test_i16_generic_sign_comparison
+    );
+    test_sign_comparison!(
+        int_same_sign::<i128>,
+        int_opposite_sign::<i128>,
+        i128,
+        test_i128_generic_sign_comparison
+    );
+
+    /// Test that the generic sign comparison treats all unsigned values as positive.
+    #[test]
+    fn test_unsigned_generic_sign_comparison() {
+        assert!(int_same_sign(0u32, u32::MAX));
+        assert!(!int_opposite_sign(u64::MAX, 1u64));
+    }
 }
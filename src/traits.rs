@@ -14,6 +14,7 @@ use core::{cmp, ops};
 /// A primitive integer.
 pub trait BaseInt:
     Sized
+    + Copy
     + ops::BitXor<Output = Self>
     + ops::BitXorAssign
     + ops::BitAnd<Output = Self>
@@ -59,6 +60,10 @@ pub trait BaseInt:
     fn rotate_left(self, n: u32) -> Self;
     /// Performs primitive typecast from u64 to T.
     fn from_u64(n: u64) -> Self;
+    /// Returns the number of leading zeros in the binary representation.
+    fn leading_zeros(self) -> u32;
+    /// Reverses the order of bits, the least significant bit becomes the most significant bit.
+    fn reverse_bits(self) -> Self;
 }
 
 macro_rules! impl_type_const {
@@ -84,9 +89,28 @@ macro_rules! impl_type_const {
             fn from_u64(n: u64) -> Self {
                 n as $type
             }
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                self.leading_zeros()
+            }
+            #[inline]
+            fn reverse_bits(self) -> Self {
+                self.reverse_bits()
+            }
         }
     )*};
 }
 
 impl_type_const!(u8, u16, u32, u64, u128, usize);
 impl_type_const!(i8, i16, i32, i64, i128, isize);
+
+/// A primitive signed integer.
+pub trait SignedInt: BaseInt {}
+
+macro_rules! impl_signed_int {
+    ($($type:ty),*) => {$(
+        impl SignedInt for $type {}
+    )*};
+}
+
+impl_signed_int!(i8, i16, i32, i64, i128, isize);